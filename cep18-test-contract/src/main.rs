@@ -6,6 +6,7 @@ extern crate alloc;
 use alloc::{
     string::{String, ToString},
     vec,
+    vec::Vec,
 };
 
 use casper_contract::{
@@ -15,8 +16,11 @@ use casper_contract::{
 };
 
 use casper_types::{
-    bytesrepr::ToBytes, runtime_args, CLTyped, ContractHash, EntryPoint, EntryPointAccess,
-    EntryPointType, EntryPoints, Key, Parameter, RuntimeArgs, U256,
+    bytesrepr::{Bytes, ToBytes},
+    runtime_args,
+    system::CallStackElement,
+    ApiError, CLTyped, CLValue, ContractHash, EntryPoint, EntryPointAccess, EntryPointType,
+    EntryPoints, Key, Parameter, RuntimeArgs, U256,
 };
 
 const CHECK_TOTAL_SUPPLY_ENTRY_POINT_NAME: &str = "check_total_supply";
@@ -24,15 +28,60 @@ const CHECK_BALANCE_OF_ENTRY_POINT_NAME: &str = "check_balance_of";
 const TRANSFER_AS_STORED_CONTRACT_ENTRY_POINT_NAME: &str = "transfer_as_stored_contract";
 const APPROVE_AS_STORED_CONTRACT_ENTRY_POINT_NAME: &str = "approve_as_stored_contract";
 const TRANSFER_FROM_AS_STORED_CONTRACT_ENTRY_POINT_NAME: &str = "transfer_from_as_stored_contract";
+const REGISTER_AS_RECEIVER_ENTRY_POINT_NAME: &str = "register_as_receiver";
+const UNREGISTER_AS_RECEIVER_ENTRY_POINT_NAME: &str = "unregister_as_receiver";
 const CHECK_ALLOWANCE_OF_ENTRY_POINT_NAME: &str = "check_allowance_of";
+const CHECK_ALLOWANCE_WITH_EXPIRY_OF_ENTRY_POINT_NAME: &str = "check_allowance_with_expiry_of";
+const CHECK_HAS_ROLE_ENTRY_POINT_NAME: &str = "check_has_role";
+const CHECK_ROLE_MEMBERS_ENTRY_POINT_NAME: &str = "check_role_members";
+const CHECK_ADMINS_ENTRY_POINT_NAME: &str = "check_admins";
+const CHECK_MINTERS_ENTRY_POINT_NAME: &str = "check_minters";
+const CHECK_BADGE_ENTRY_POINT_NAME: &str = "check_badge";
+const CHECK_BALANCE_OF_AT_ENTRY_POINT_NAME: &str = "check_balance_of_at";
+const CHECK_TOTAL_SUPPLY_AT_ENTRY_POINT_NAME: &str = "check_total_supply_at";
+const CHECK_VOTES_ENTRY_POINT_NAME: &str = "check_votes";
+const CHECK_PAST_VOTES_ENTRY_POINT_NAME: &str = "check_past_votes";
+const CHECK_LOCKED_BALANCE_OF_ENTRY_POINT_NAME: &str = "check_locked_balance_of";
+const CHECK_VESTED_AMOUNT_ENTRY_POINT_NAME: &str = "check_vested_amount";
+const CHECK_MAX_FLASH_LOAN_ENTRY_POINT_NAME: &str = "check_max_flash_loan";
+const ON_CEP18_RECEIVED_ENTRY_POINT_NAME: &str = "on_cep18_received";
+const ON_FLASH_LOAN_ENTRY_POINT_NAME: &str = "on_flash_loan";
 const TOKEN_CONTRACT_RUNTIME_ARG_NAME: &str = "token_contract";
 const ADDRESS_RUNTIME_ARG_NAME: &str = "address";
 const OWNER_RUNTIME_ARG_NAME: &str = "owner";
 const SPENDER_RUNTIME_ARG_NAME: &str = "spender";
+const ACCOUNT_RUNTIME_ARG_NAME: &str = "account";
+const ROLE_RUNTIME_ARG_NAME: &str = "role";
+const OFFSET_RUNTIME_ARG_NAME: &str = "offset";
+const LIMIT_RUNTIME_ARG_NAME: &str = "limit";
+const SNAPSHOT_ID_RUNTIME_ARG_NAME: &str = "snapshot_id";
+const BLOCKTIME_RUNTIME_ARG_NAME: &str = "blocktime";
+const SENDER_RUNTIME_ARG_NAME: &str = "sender";
+const DATA_RUNTIME_ARG_NAME: &str = "data";
+const INITIATOR_RUNTIME_ARG_NAME: &str = "initiator";
+const FEE_RUNTIME_ARG_NAME: &str = "fee";
 const RESULT_KEY: &str = "result";
 const CEP18_TEST_CALL_KEY: &str = "cep18_test_contract";
+/// `on_cep18_received` and `on_flash_loan` reject tokens sent along with this data.
+const REJECT_DATA: &[u8] = b"reject";
+/// `on_cep18_received` reverts with `RECEIVER_REVERT_ERROR` on tokens sent along with this data.
+const REVERT_DATA: &[u8] = b"revert";
+const RECEIVER_REVERT_ERROR: u16 = 1;
 
 const ALLOWANCE_ENTRY_POINT_NAME: &str = "allowance";
+const ALLOWANCE_WITH_EXPIRY_ENTRY_POINT_NAME: &str = "allowance_with_expiry";
+const HAS_ROLE_ENTRY_POINT_NAME: &str = "has_role";
+const GET_ROLE_MEMBERS_ENTRY_POINT_NAME: &str = "get_role_members";
+const GET_ADMINS_ENTRY_POINT_NAME: &str = "get_admins";
+const GET_MINTERS_ENTRY_POINT_NAME: &str = "get_minters";
+const GET_BADGE_ENTRY_POINT_NAME: &str = "get_badge";
+const BALANCE_OF_AT_ENTRY_POINT_NAME: &str = "balance_of_at";
+const TOTAL_SUPPLY_AT_ENTRY_POINT_NAME: &str = "total_supply_at";
+const GET_VOTES_ENTRY_POINT_NAME: &str = "get_votes";
+const GET_PAST_VOTES_ENTRY_POINT_NAME: &str = "get_past_votes";
+const LOCKED_BALANCE_OF_ENTRY_POINT_NAME: &str = "locked_balance_of";
+const VESTED_AMOUNT_ENTRY_POINT_NAME: &str = "vested_amount";
+const MAX_FLASH_LOAN_ENTRY_POINT_NAME: &str = "max_flash_loan";
 const RECIPIENT_RUNTIME_ARG_NAME: &str = "recipient";
 const AMOUNT_RUNTIME_ARG_NAME: &str = "amount";
 const APPROVE_ENTRY_POINT_NAME: &str = "approve";
@@ -40,6 +89,8 @@ const TOTAL_SUPPLY_ENTRY_POINT_NAME: &str = "total_supply";
 const BALANCE_OF_ENTRY_POINT_NAME: &str = "balance_of";
 const TRANSFER_ENTRY_POINT_NAME: &str = "transfer";
 const TRANSFER_FROM_ENTRY_POINT_NAME: &str = "transfer_from";
+const REGISTER_RECEIVER_ENTRY_POINT_NAME: &str = "register_receiver";
+const UNREGISTER_RECEIVER_ENTRY_POINT_NAME: &str = "unregister_receiver";
 
 fn store_result<T: CLTyped + ToBytes>(result: T) {
     match runtime::get_key(RESULT_KEY) {
@@ -105,6 +156,316 @@ extern "C" fn check_allowance_of() {
     store_result(result);
 }
 
+#[no_mangle]
+extern "C" fn check_allowance_with_expiry_of() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let owner: Key = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+    let spender: Key = runtime::get_named_arg(SPENDER_RUNTIME_ARG_NAME);
+
+    let allowance_args = runtime_args! {
+        OWNER_RUNTIME_ARG_NAME => owner,
+        SPENDER_RUNTIME_ARG_NAME => spender,
+    };
+    let result: (U256, Option<u64>) = runtime::call_contract(
+        token_contract,
+        ALLOWANCE_WITH_EXPIRY_ENTRY_POINT_NAME,
+        allowance_args,
+    );
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_has_role() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let account: Key = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+    let role: u8 = runtime::get_named_arg(ROLE_RUNTIME_ARG_NAME);
+
+    let has_role_args = runtime_args! {
+        ACCOUNT_RUNTIME_ARG_NAME => account,
+        ROLE_RUNTIME_ARG_NAME => role,
+    };
+    let result: bool =
+        runtime::call_contract(token_contract, HAS_ROLE_ENTRY_POINT_NAME, has_role_args);
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_role_members() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let role: u8 = runtime::get_named_arg(ROLE_RUNTIME_ARG_NAME);
+    let offset: u32 = runtime::get_named_arg(OFFSET_RUNTIME_ARG_NAME);
+    let limit: u32 = runtime::get_named_arg(LIMIT_RUNTIME_ARG_NAME);
+
+    let role_members_args = runtime_args! {
+        ROLE_RUNTIME_ARG_NAME => role,
+        OFFSET_RUNTIME_ARG_NAME => offset,
+        LIMIT_RUNTIME_ARG_NAME => limit,
+    };
+    let result: Vec<Key> = runtime::call_contract(
+        token_contract,
+        GET_ROLE_MEMBERS_ENTRY_POINT_NAME,
+        role_members_args,
+    );
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_admins() {
+    check_role_getter(GET_ADMINS_ENTRY_POINT_NAME);
+}
+
+#[no_mangle]
+extern "C" fn check_minters() {
+    check_role_getter(GET_MINTERS_ENTRY_POINT_NAME);
+}
+
+/// Stores the page of role members returned by the `getter` entry point of the token contract.
+fn check_role_getter(getter: &str) {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let offset: u32 = runtime::get_named_arg(OFFSET_RUNTIME_ARG_NAME);
+    let limit: u32 = runtime::get_named_arg(LIMIT_RUNTIME_ARG_NAME);
+
+    let role_getter_args = runtime_args! {
+        OFFSET_RUNTIME_ARG_NAME => offset,
+        LIMIT_RUNTIME_ARG_NAME => limit,
+    };
+    let result: Vec<Key> = runtime::call_contract(token_contract, getter, role_getter_args);
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_badge() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let account: Option<Key> = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+
+    let mut badge_args = RuntimeArgs::new();
+    if let Some(account) = account {
+        badge_args
+            .insert(ACCOUNT_RUNTIME_ARG_NAME, account)
+            .unwrap_or_revert();
+    }
+    let result: u8 = runtime::call_contract(token_contract, GET_BADGE_ENTRY_POINT_NAME, badge_args);
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_balance_of_at() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let address: Key = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    let snapshot_id: u64 = runtime::get_named_arg(SNAPSHOT_ID_RUNTIME_ARG_NAME);
+
+    let balance_args = runtime_args! {
+        ADDRESS_RUNTIME_ARG_NAME => address,
+        SNAPSHOT_ID_RUNTIME_ARG_NAME => snapshot_id,
+    };
+    let result: U256 =
+        runtime::call_contract(token_contract, BALANCE_OF_AT_ENTRY_POINT_NAME, balance_args);
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_total_supply_at() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let snapshot_id: u64 = runtime::get_named_arg(SNAPSHOT_ID_RUNTIME_ARG_NAME);
+
+    let total_supply_args = runtime_args! {
+        SNAPSHOT_ID_RUNTIME_ARG_NAME => snapshot_id,
+    };
+    let result: U256 = runtime::call_contract(
+        token_contract,
+        TOTAL_SUPPLY_AT_ENTRY_POINT_NAME,
+        total_supply_args,
+    );
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_votes() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let account: Key = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+
+    let votes_args = runtime_args! {
+        ACCOUNT_RUNTIME_ARG_NAME => account,
+    };
+    let result: U256 =
+        runtime::call_contract(token_contract, GET_VOTES_ENTRY_POINT_NAME, votes_args);
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_past_votes() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let account: Key = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+    let blocktime: u64 = runtime::get_named_arg(BLOCKTIME_RUNTIME_ARG_NAME);
+
+    let past_votes_args = runtime_args! {
+        ACCOUNT_RUNTIME_ARG_NAME => account,
+        BLOCKTIME_RUNTIME_ARG_NAME => blocktime,
+    };
+    let result: U256 = runtime::call_contract(
+        token_contract,
+        GET_PAST_VOTES_ENTRY_POINT_NAME,
+        past_votes_args,
+    );
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_locked_balance_of() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let address: Key = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+
+    let locked_balance_of_args = runtime_args! {
+        ADDRESS_RUNTIME_ARG_NAME => address,
+    };
+    let result: U256 = runtime::call_contract(
+        token_contract,
+        LOCKED_BALANCE_OF_ENTRY_POINT_NAME,
+        locked_balance_of_args,
+    );
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_vested_amount() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let address: Key = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+
+    let vested_amount_args = runtime_args! {
+        ADDRESS_RUNTIME_ARG_NAME => address,
+    };
+    let result: U256 = runtime::call_contract(
+        token_contract,
+        VESTED_AMOUNT_ENTRY_POINT_NAME,
+        vested_amount_args,
+    );
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_max_flash_loan() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let max_flash_loan: U256 = runtime::call_contract(
+        token_contract,
+        MAX_FLASH_LOAN_ENTRY_POINT_NAME,
+        RuntimeArgs::default(),
+    );
+    store_result(max_flash_loan);
+}
+
+/// Receiver hook of `transfer_and_call` and safe transfers, storing the `(sender, amount, data)` of
+/// accepted tokens.
+#[no_mangle]
+extern "C" fn on_cep18_received() {
+    let sender: Key = runtime::get_named_arg(SENDER_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let data: Bytes = runtime::get_named_arg(DATA_RUNTIME_ARG_NAME);
+
+    if data.as_ref() == REVERT_DATA {
+        runtime::revert(ApiError::User(RECEIVER_REVERT_ERROR));
+    }
+    let accepted = data.as_ref() != REJECT_DATA;
+    if accepted {
+        store_result((sender, amount, data));
+    }
+
+    runtime::ret(CLValue::from_t(accepted).unwrap_or_revert());
+}
+
+/// Borrower hook of `flash_mint`, storing the `(initiator, fee, balance)` of accepted loans, the
+/// balance being queried from the token during the loan.
+#[no_mangle]
+extern "C" fn on_flash_loan() {
+    let initiator: Key = runtime::get_named_arg(INITIATOR_RUNTIME_ARG_NAME);
+    let fee: U256 = runtime::get_named_arg(FEE_RUNTIME_ARG_NAME);
+    let data: Bytes = runtime::get_named_arg(DATA_RUNTIME_ARG_NAME);
+
+    let accepted = data.as_ref() != REJECT_DATA;
+    if accepted {
+        let mut call_stack = runtime::get_call_stack();
+        let borrower = match call_stack.pop() {
+            Some(CallStackElement::StoredContract {
+                contract_package_hash,
+                ..
+            }) => Key::from(contract_package_hash),
+            _ => runtime::revert(ApiError::Unhandled),
+        };
+        let token_contract = match call_stack.pop() {
+            Some(CallStackElement::StoredContract { contract_hash, .. }) => contract_hash,
+            _ => runtime::revert(ApiError::Unhandled),
+        };
+        let balance: U256 = runtime::call_contract(
+            token_contract,
+            BALANCE_OF_ENTRY_POINT_NAME,
+            runtime_args! {
+                ADDRESS_RUNTIME_ARG_NAME => borrower,
+            },
+        );
+        store_result((initiator, fee, balance));
+    }
+
+    runtime::ret(CLValue::from_t(accepted).unwrap_or_revert());
+}
+
 #[no_mangle]
 extern "C" fn transfer_as_stored_contract() {
     let token_contract: ContractHash = ContractHash::new(
@@ -147,6 +508,36 @@ extern "C" fn transfer_from_as_stored_contract() {
     );
 }
 
+#[no_mangle]
+extern "C" fn register_as_receiver() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+
+    runtime::call_contract::<()>(
+        token_contract,
+        REGISTER_RECEIVER_ENTRY_POINT_NAME,
+        RuntimeArgs::default(),
+    );
+}
+
+#[no_mangle]
+extern "C" fn unregister_as_receiver() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+
+    runtime::call_contract::<()>(
+        token_contract,
+        UNREGISTER_RECEIVER_ENTRY_POINT_NAME,
+        RuntimeArgs::default(),
+    );
+}
+
 #[no_mangle]
 extern "C" fn approve_as_stored_contract() {
     let token_contract: ContractHash = ContractHash::new(
@@ -199,6 +590,168 @@ pub extern "C" fn call() {
         EntryPointAccess::Public,
         EntryPointType::Contract,
     );
+    let check_allowance_with_expiry_of_entrypoint = EntryPoint::new(
+        String::from(CHECK_ALLOWANCE_WITH_EXPIRY_OF_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(OWNER_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(SPENDER_RUNTIME_ARG_NAME, Key::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_has_role_entrypoint = EntryPoint::new(
+        String::from(CHECK_HAS_ROLE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(ROLE_RUNTIME_ARG_NAME, u8::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_role_members_entrypoint = EntryPoint::new(
+        String::from(CHECK_ROLE_MEMBERS_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(ROLE_RUNTIME_ARG_NAME, u8::cl_type()),
+            Parameter::new(OFFSET_RUNTIME_ARG_NAME, u32::cl_type()),
+            Parameter::new(LIMIT_RUNTIME_ARG_NAME, u32::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_admins_entrypoint = EntryPoint::new(
+        String::from(CHECK_ADMINS_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(OFFSET_RUNTIME_ARG_NAME, u32::cl_type()),
+            Parameter::new(LIMIT_RUNTIME_ARG_NAME, u32::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_minters_entrypoint = EntryPoint::new(
+        String::from(CHECK_MINTERS_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(OFFSET_RUNTIME_ARG_NAME, u32::cl_type()),
+            Parameter::new(LIMIT_RUNTIME_ARG_NAME, u32::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_badge_entrypoint = EntryPoint::new(
+        String::from(CHECK_BADGE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Option::<Key>::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_balance_of_at_entrypoint = EntryPoint::new(
+        String::from(CHECK_BALANCE_OF_AT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(SNAPSHOT_ID_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_total_supply_at_entrypoint = EntryPoint::new(
+        String::from(CHECK_TOTAL_SUPPLY_AT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(SNAPSHOT_ID_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_votes_entrypoint = EntryPoint::new(
+        String::from(CHECK_VOTES_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Key::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_past_votes_entrypoint = EntryPoint::new(
+        String::from(CHECK_PAST_VOTES_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(BLOCKTIME_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_locked_balance_of_entrypoint = EntryPoint::new(
+        String::from(CHECK_LOCKED_BALANCE_OF_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Key::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_vested_amount_entrypoint = EntryPoint::new(
+        String::from(CHECK_VESTED_AMOUNT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Key::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_max_flash_loan_entrypoint = EntryPoint::new(
+        String::from(CHECK_MAX_FLASH_LOAN_ENTRY_POINT_NAME),
+        vec![Parameter::new(
+            TOKEN_CONTRACT_RUNTIME_ARG_NAME,
+            ContractHash::cl_type(),
+        )],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let on_cep18_received_entrypoint = EntryPoint::new(
+        String::from(ON_CEP18_RECEIVED_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(SENDER_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(DATA_RUNTIME_ARG_NAME, Bytes::cl_type()),
+        ],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    let on_flash_loan_entrypoint = EntryPoint::new(
+        String::from(ON_FLASH_LOAN_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(INITIATOR_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(FEE_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(DATA_RUNTIME_ARG_NAME, Bytes::cl_type()),
+        ],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
 
     let transfer_as_stored_contract_entrypoint = EntryPoint::new(
         String::from(TRANSFER_AS_STORED_CONTRACT_ENTRY_POINT_NAME),
@@ -237,12 +790,51 @@ pub extern "C" fn call() {
         EntryPointType::Contract,
     );
 
+    let register_as_receiver_entrypoint = EntryPoint::new(
+        String::from(REGISTER_AS_RECEIVER_ENTRY_POINT_NAME),
+        vec![Parameter::new(
+            TOKEN_CONTRACT_RUNTIME_ARG_NAME,
+            ContractHash::cl_type(),
+        )],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    let unregister_as_receiver_entrypoint = EntryPoint::new(
+        String::from(UNREGISTER_AS_RECEIVER_ENTRY_POINT_NAME),
+        vec![Parameter::new(
+            TOKEN_CONTRACT_RUNTIME_ARG_NAME,
+            ContractHash::cl_type(),
+        )],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
     entry_points.add_entry_point(check_total_supply_entrypoint);
     entry_points.add_entry_point(check_balance_of_entrypoint);
     entry_points.add_entry_point(check_allowance_of_entrypoint);
+    entry_points.add_entry_point(check_allowance_with_expiry_of_entrypoint);
+    entry_points.add_entry_point(check_has_role_entrypoint);
+    entry_points.add_entry_point(check_role_members_entrypoint);
+    entry_points.add_entry_point(check_admins_entrypoint);
+    entry_points.add_entry_point(check_minters_entrypoint);
+    entry_points.add_entry_point(check_badge_entrypoint);
+    entry_points.add_entry_point(check_balance_of_at_entrypoint);
+    entry_points.add_entry_point(check_total_supply_at_entrypoint);
+    entry_points.add_entry_point(check_votes_entrypoint);
+    entry_points.add_entry_point(check_past_votes_entrypoint);
+    entry_points.add_entry_point(check_locked_balance_of_entrypoint);
+    entry_points.add_entry_point(check_vested_amount_entrypoint);
+    entry_points.add_entry_point(check_max_flash_loan_entrypoint);
+    entry_points.add_entry_point(on_cep18_received_entrypoint);
+    entry_points.add_entry_point(on_flash_loan_entrypoint);
     entry_points.add_entry_point(transfer_as_stored_contract_entrypoint);
     entry_points.add_entry_point(approve_as_stored_contract_entrypoint);
     entry_points.add_entry_point(transfer_from_as_stored_contract_entrypoint);
+    entry_points.add_entry_point(register_as_receiver_entrypoint);
+    entry_points.add_entry_point(unregister_as_receiver_entrypoint);
 
     let (_contract_hash, _version) = storage::new_contract(
         entry_points,
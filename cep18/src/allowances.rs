@@ -7,7 +7,10 @@ use casper_contract::{
 };
 use casper_types::{bytesrepr::ToBytes, Key, URef, U256};
 
-use crate::{constants::ALLOWANCES, utils};
+use crate::{
+    constants::{ALLOWANCES, EXPIRES_AT},
+    utils,
+};
 
 #[inline]
 pub(crate) fn get_allowances_uref() -> URef {
@@ -24,16 +27,62 @@ pub(crate) fn make_dictionary_item_key(owner: Key, spender: Key) -> String {
     hex::encode(key_bytes)
 }
 
+/// Creates a dictionary item key under which the expiry of an (owner, spender) allowance is
+/// kept.
+///
+/// The expiry lives next to the amount in the same dictionary, under a key that cannot collide
+/// with the amount's, so contracts upgraded from an earlier version need no new dictionary.
+fn make_expiry_dictionary_item_key(owner: Key, spender: Key) -> String {
+    let mut preimage = Vec::new();
+    preimage.append(&mut owner.to_bytes().unwrap_or_revert());
+    preimage.append(&mut spender.to_bytes().unwrap_or_revert());
+    preimage.extend_from_slice(EXPIRES_AT.as_bytes());
+
+    let key_bytes = runtime::blake2b(&preimage);
+    hex::encode(key_bytes)
+}
+
 /// Writes an allowance for owner and spender for a specific amount.
 pub(crate) fn write_allowance_to(allowance_uref: URef, owner: Key, spender: Key, amount: U256) {
     let dictionary_item_key = make_dictionary_item_key(owner, spender);
     storage::dictionary_put(allowance_uref, &dictionary_item_key, amount)
 }
 
-/// Reads an allowance for a owner and spender
+/// Reads an allowance for a owner and spender.
+///
+/// Allowances past their expiry read as zero.
 pub(crate) fn read_allowance_from(allowances_uref: URef, owner: Key, spender: Key) -> U256 {
+    if let Some(expires_at) = read_allowance_expiry_from(allowances_uref, owner, spender) {
+        if u64::from(runtime::get_blocktime()) >= expires_at {
+            return U256::zero();
+        }
+    }
     let dictionary_item_key = make_dictionary_item_key(owner, spender);
     storage::dictionary_get(allowances_uref, &dictionary_item_key)
         .unwrap_or_revert()
         .unwrap_or_default()
 }
+
+/// Writes the block time at which the allowance for owner and spender expires, `None` meaning
+/// it never expires.
+pub(crate) fn write_allowance_expiry_to(
+    allowances_uref: URef,
+    owner: Key,
+    spender: Key,
+    expires_at: Option<u64>,
+) {
+    let dictionary_item_key = make_expiry_dictionary_item_key(owner, spender);
+    storage::dictionary_put(allowances_uref, &dictionary_item_key, expires_at)
+}
+
+/// Reads the block time at which the allowance for owner and spender expires, if any.
+pub(crate) fn read_allowance_expiry_from(
+    allowances_uref: URef,
+    owner: Key,
+    spender: Key,
+) -> Option<u64> {
+    let dictionary_item_key = make_expiry_dictionary_item_key(owner, spender);
+    storage::dictionary_get::<Option<u64>>(allowances_uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .flatten()
+}
@@ -0,0 +1,48 @@
+//! Implementation of the allowlist transfer restriction.
+use core::convert::TryFrom;
+
+use alloc::collections::BTreeMap;
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{Key, URef};
+
+use crate::{
+    constants::{ALLOWLIST, TRANSFER_RESTRICTION},
+    error::Cep18Error,
+    modalities::TransferRestriction,
+    utils::{self, make_dictionary_item_key},
+};
+
+/// Getter for the "allowlist" dictionary URef.
+pub(crate) fn get_allowlist_uref() -> URef {
+    utils::get_uref(ALLOWLIST)
+}
+
+/// Returns `true` if the `Allowlist` transfer restriction was selected at install.
+pub(crate) fn allowlist_enabled() -> bool {
+    let restriction = utils::read_optional_from::<u8>(TRANSFER_RESTRICTION).unwrap_or_default();
+    TransferRestriction::try_from(restriction).unwrap_or_revert() == TransferRestriction::Allowlist
+}
+
+/// Reads whether a specified account is on the allowlist.
+pub(crate) fn read_allowlisted_from(allowlist_uref: URef, address: Key) -> bool {
+    let dictionary_item_key = make_dictionary_item_key(address);
+    storage::dictionary_get(allowlist_uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Returns `error` if the allowlist restriction is enabled and the given account is not on it.
+pub(crate) fn check_allowlisted(address: Key, error: Cep18Error) -> Result<(), Cep18Error> {
+    if allowlist_enabled() && !read_allowlisted_from(get_allowlist_uref(), address) {
+        return Err(error);
+    }
+    Ok(())
+}
+
+/// Adds or removes accounts from the allowlist.
+pub(crate) fn change_allowlist(allowlist_change_map: &BTreeMap<Key, bool>) {
+    let allowlist_uref = get_allowlist_uref();
+    for (&address, &allowed) in allowlist_change_map {
+        storage::dictionary_put(allowlist_uref, &make_dictionary_item_key(address), allowed);
+    }
+}
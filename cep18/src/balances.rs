@@ -1,24 +1,15 @@
 //! Implementation of balances.
-use alloc::string::String;
-
 use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
-use casper_types::{bytesrepr::ToBytes, Key, URef, U256};
-
-use crate::{constants::BALANCES, error::Cep18Error, utils};
-
-/// Creates a dictionary item key for a dictionary item, by base64 encoding the Key argument
-/// since stringified Keys are too long to be used as dictionary keys.
-#[inline]
-fn make_dictionary_item_key(owner: Key) -> String {
-    let preimage = owner.to_bytes().unwrap_or_revert();
-    // NOTE: As for now dictionary item keys are limited to 64 characters only. Instead of using
-    // hashing (which will effectively hash a hash) we'll use base64. Preimage is 33 bytes for
-    // both used Key variants, and approximated base64-encoded length will be 4 * (33 / 3) ~ 44
-    // characters.
-    // Even if the preimage increased in size we still have extra space but even in case of much
-    // larger preimage we can switch to base85 which has ratio of 4:5.
-    base64::encode(preimage)
-}
+use casper_types::{Key, URef, U256};
+
+use crate::{
+    allowlist,
+    constants::BALANCES,
+    error::Cep18Error,
+    frozen, snapshots,
+    utils::{self, make_dictionary_item_key},
+    vesting, votes,
+};
 
 /// Getter for the "balances" dictionary URef.
 pub(crate) fn get_balances_uref() -> URef {
@@ -26,7 +17,10 @@ pub(crate) fn get_balances_uref() -> URef {
 }
 
 /// Writes token balance of a specified account into a dictionary.
+///
+/// The previous balance is checkpointed first if a snapshot was taken since it was last written.
 pub(crate) fn write_balance_to(balances_uref: URef, address: Key, amount: U256) {
+    snapshots::update_balance_snapshot(balances_uref, address);
     let dictionary_item_key = make_dictionary_item_key(address);
     storage::dictionary_put(balances_uref, &dictionary_item_key, amount);
 }
@@ -42,36 +36,131 @@ pub(crate) fn read_balance_from(balances_uref: URef, address: Key) -> U256 {
         .unwrap_or_default()
 }
 
-/// Transfer tokens from the `sender` to the `recipient`.
+/// Transfer tokens from the `sender` to each of the `(recipient, amount)` pairs.
+///
+/// The sender balance is read and written once for the whole list. Transfers to the sender itself
+/// and zero amounts are skipped, once the sender and the recipient were checked. The voting power
+/// of the delegates of the sender and recipients moves along with the tokens. Only the unlocked
+/// part of the sender balance can be spent.
 ///
 /// This function should not be used directly by contract's entrypoint as it does not validate the
 /// sender.
-pub(crate) fn transfer_balance(
+pub(crate) fn transfer_balances(sender: Key, transfers: &[(Key, U256)]) -> Result<(), Cep18Error> {
+    frozen::check_not_frozen(sender)?;
+    allowlist::check_allowlisted(sender, Cep18Error::SenderNotAllowlisted)?;
+    let balances_uref = get_balances_uref();
+    let mut sender_balance: Option<U256> = None;
+    let sender_delegate = votes::read_delegate_from(sender);
+
+    for &(recipient, amount) in transfers {
+        frozen::check_not_frozen(recipient)?;
+        allowlist::check_allowlisted(recipient, Cep18Error::RecipientNotAllowlisted)?;
+        if sender == recipient || amount.is_zero() {
+            continue;
+        }
+        let current_sender_balance =
+            sender_balance.unwrap_or_else(|| read_balance_from(balances_uref, sender));
+
+        sender_balance = Some(
+            current_sender_balance
+                .checked_sub(amount)
+                .ok_or(Cep18Error::InsufficientBalance)?,
+        );
+
+        let new_recipient_balance = {
+            let recipient_balance = read_balance_from(balances_uref, recipient);
+            recipient_balance
+                .checked_add(amount)
+                .ok_or(Cep18Error::Overflow)?
+        };
+        write_balance_to(balances_uref, recipient, new_recipient_balance);
+        votes::move_voting_power(
+            sender_delegate,
+            votes::read_delegate_from(recipient),
+            amount,
+        )?;
+    }
+
+    if let Some(new_sender_balance) = sender_balance {
+        vesting::check_unlocked(sender, new_sender_balance)?;
+        write_balance_to(balances_uref, sender, new_sender_balance);
+    }
+
+    Ok(())
+}
+
+/// Moves a transfer `fee` from the `sender` to the `treasury`.
+///
+/// Unlike [`transfer_balances`], the treasury is neither checked for a freeze nor against the
+/// allowlist, so that it cannot block the transfers the fee is charged on. The sender is checked
+/// by these transfers. Only the unlocked part of the sender balance can be spent.
+pub(crate) fn transfer_fee_balance(
     sender: Key,
-    recipient: Key,
-    amount: U256,
+    treasury: Key,
+    fee: U256,
 ) -> Result<(), Cep18Error> {
-    if sender == recipient || amount.is_zero() {
-        return Ok(());
-    }
+    let balances_uref = get_balances_uref();
+    let new_sender_balance = read_balance_from(balances_uref, sender)
+        .checked_sub(fee)
+        .ok_or(Cep18Error::InsufficientBalance)?;
+    vesting::check_unlocked(sender, new_sender_balance)?;
+    let new_treasury_balance = read_balance_from(balances_uref, treasury)
+        .checked_add(fee)
+        .ok_or(Cep18Error::Overflow)?;
+    write_balance_to(balances_uref, sender, new_sender_balance);
+    write_balance_to(balances_uref, treasury, new_treasury_balance);
+    votes::move_voting_power(
+        votes::read_delegate_from(sender),
+        votes::read_delegate_from(treasury),
+        fee,
+    )
+}
 
+/// Mints tokens to each of the `(owner, amount)` pairs, updating the total supply once and adding
+/// voting power to the delegates of the owners.
+///
+/// This function does not check whether the caller is allowed to mint, nor whether the owners are
+/// allowlisted or frozen.
+pub(crate) fn mint_balances(mints: &[(Key, U256)]) -> Result<(), Cep18Error> {
     let balances_uref = get_balances_uref();
-    let new_sender_balance = {
-        let sender_balance = read_balance_from(balances_uref, sender);
-        sender_balance
-            .checked_sub(amount)
-            .ok_or(Cep18Error::InsufficientBalance)?
-    };
-
-    let new_recipient_balance = {
-        let recipient_balance = read_balance_from(balances_uref, recipient);
-        recipient_balance
+    let total_supply_uref = utils::get_total_supply_uref();
+
+    let mut new_total_supply = utils::read_total_supply_from(total_supply_uref);
+    for &(owner, amount) in mints {
+        let new_balance = read_balance_from(balances_uref, owner)
+            .checked_add(amount)
+            .ok_or(Cep18Error::Overflow)?;
+        new_total_supply = new_total_supply
             .checked_add(amount)
-            .ok_or(Cep18Error::Overflow)?
-    };
+            .ok_or(Cep18Error::Overflow)?;
+        write_balance_to(balances_uref, owner, new_balance);
+        votes::move_voting_power(None, votes::read_delegate_from(owner), amount)?;
+    }
+    if new_total_supply > utils::read_max_supply() {
+        return Err(Cep18Error::ExceedsMaxSupply);
+    }
+    utils::write_total_supply_to(total_supply_uref, new_total_supply);
 
-    write_balance_to(balances_uref, sender, new_sender_balance);
-    write_balance_to(balances_uref, recipient, new_recipient_balance);
+    Ok(())
+}
+
+/// Burns tokens of a specified account, decreasing the total supply and the voting power of its
+/// delegate. Only the unlocked part of the balance can be burned.
+///
+/// This function does not check whether the caller is allowed to burn the owner's tokens.
+pub(crate) fn burn_balance(owner: Key, amount: U256) -> Result<(), Cep18Error> {
+    let balances_uref = get_balances_uref();
+    let total_supply_uref = utils::get_total_supply_uref();
+    let new_balance = read_balance_from(balances_uref, owner)
+        .checked_sub(amount)
+        .ok_or(Cep18Error::InsufficientBalance)?;
+    vesting::check_unlocked(owner, new_balance)?;
+    let new_total_supply = utils::read_total_supply_from(total_supply_uref)
+        .checked_sub(amount)
+        .ok_or(Cep18Error::Overflow)?;
+    write_balance_to(balances_uref, owner, new_balance);
+    utils::write_total_supply_to(total_supply_uref, new_total_supply);
+    votes::move_voting_power(votes::read_delegate_from(owner), None, amount)?;
 
     Ok(())
 }
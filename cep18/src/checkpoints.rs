@@ -0,0 +1,98 @@
+//! Implementation of checkpoints, lists of `(id, value)` pairs ordered by increasing id and
+//! stored in a dictionary.
+//!
+//! The number of checkpoints of a list is stored under the list's item key, and each checkpoint
+//! under `"{list}_{index}"`, the index being base64 encoded like the other item keys.
+use alloc::string::String;
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{URef, U256};
+
+/// Returns the item key of the checkpoint of a list at a given index.
+fn checkpoint_item_key(list: &str, index: u32) -> String {
+    let mut item_key = String::from(list);
+    item_key.push('_');
+    item_key.push_str(&base64::encode(index.to_be_bytes()));
+    item_key
+}
+
+/// Reads the number of checkpoints of a list.
+fn read_length(checkpoints_uref: URef, list: &str) -> u32 {
+    storage::dictionary_get(checkpoints_uref, list)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Reads the checkpoint of a list at a given index.
+fn read_checkpoint(checkpoints_uref: URef, list: &str, index: u32) -> (u64, U256) {
+    storage::dictionary_get(checkpoints_uref, &checkpoint_item_key(list, index))
+        .unwrap_or_revert()
+        .unwrap_or_revert()
+}
+
+/// Returns the latest checkpoint of a list, if any.
+pub(crate) fn latest(checkpoints_uref: URef, list: &str) -> Option<(u64, U256)> {
+    let length = read_length(checkpoints_uref, list);
+    if length == 0 {
+        return None;
+    }
+    Some(read_checkpoint(checkpoints_uref, list, length - 1))
+}
+
+/// Records a checkpoint in a list. The id must not be lower than the id of the latest checkpoint,
+/// which is overwritten if it has the same id.
+pub(crate) fn push(checkpoints_uref: URef, list: &str, id: u64, value: U256) {
+    let length = read_length(checkpoints_uref, list);
+    if length > 0 && read_checkpoint(checkpoints_uref, list, length - 1).0 == id {
+        storage::dictionary_put(
+            checkpoints_uref,
+            &checkpoint_item_key(list, length - 1),
+            (id, value),
+        );
+        return;
+    }
+    storage::dictionary_put(
+        checkpoints_uref,
+        &checkpoint_item_key(list, length),
+        (id, value),
+    );
+    storage::dictionary_put(checkpoints_uref, list, length + 1);
+}
+
+/// Returns the value of the first checkpoint of a list whose id is greater than or equal to `id`,
+/// found by binary search.
+pub(crate) fn upper_lookup(checkpoints_uref: URef, list: &str, id: u64) -> Option<U256> {
+    let length = read_length(checkpoints_uref, list);
+    let mut low = 0;
+    let mut high = length;
+    while low < high {
+        let middle = low + (high - low) / 2;
+        if read_checkpoint(checkpoints_uref, list, middle).0 < id {
+            low = middle + 1;
+        } else {
+            high = middle;
+        }
+    }
+    if low == length {
+        return None;
+    }
+    Some(read_checkpoint(checkpoints_uref, list, low).1)
+}
+
+/// Returns the value of the last checkpoint of a list whose id is lower than or equal to `id`,
+/// found by binary search.
+pub(crate) fn lower_lookup(checkpoints_uref: URef, list: &str, id: u64) -> Option<U256> {
+    let mut low = 0;
+    let mut high = read_length(checkpoints_uref, list);
+    while low < high {
+        let middle = low + (high - low) / 2;
+        if read_checkpoint(checkpoints_uref, list, middle).0 > id {
+            high = middle;
+        } else {
+            low = middle + 1;
+        }
+    }
+    if high == 0 {
+        return None;
+    }
+    Some(read_checkpoint(checkpoints_uref, list, high - 1).1)
+}
@@ -10,8 +10,40 @@ pub const DECIMALS: &str = "decimals";
 pub const BALANCES: &str = "balances";
 /// Name of dictionary-key for `allowances`
 pub const ALLOWANCES: &str = "allowances";
+/// Name of dictionary-key for `nonces`
+pub const NONCES: &str = "nonces";
+/// Name of dictionary-key for `frozen`
+pub const FROZEN: &str = "frozen";
+/// Name of dictionary-key for `allowlist`
+pub const ALLOWLIST: &str = "allowlist";
+/// Name of dictionary-key for `roles`
+pub const ROLES: &str = "roles";
+/// Name of dictionary-key for `admin_nominations`
+pub const ADMIN_NOMINATIONS: &str = "admin_nominations";
+/// Name of dictionary-key for `role_members`
+pub const ROLE_MEMBERS: &str = "role_members";
+/// Name of dictionary-key for `role_member_indices`
+pub const ROLE_MEMBER_INDICES: &str = "role_member_indices";
+/// Name of dictionary-key for `minter_allowance`
+pub const MINTER_ALLOWANCE: &str = "minter_allowance";
+/// Name of dictionary-key for `mint_buckets`
+pub const MINT_BUCKETS: &str = "mint_buckets";
+/// Name of dictionary-key for `balance_snapshots`
+pub const BALANCE_SNAPSHOTS: &str = "balance_snapshots";
+/// Name of dictionary-key for `total_supply_snapshots`
+pub const TOTAL_SUPPLY_SNAPSHOTS: &str = "total_supply_snapshots";
+/// Name of named-key for `current_snapshot_id`
+pub const CURRENT_SNAPSHOT_ID: &str = "current_snapshot_id";
+/// Name of dictionary-key for `delegates`
+pub const DELEGATES: &str = "delegates";
+/// Name of dictionary-key for `vote_checkpoints`
+pub const VOTE_CHECKPOINTS: &str = "vote_checkpoints";
+/// Name of dictionary-key for `vesting_schedules`
+pub const VESTING_SCHEDULES: &str = "vesting_schedules";
 /// Name of named-key for `total_supply`
 pub const TOTAL_SUPPLY: &str = "total_supply";
+/// Name of named-key for `max_supply`
+pub const MAX_SUPPLY: &str = "max_supply";
 
 pub const HASH_KEY_NAME_PREFIX: &str = "cep18_contract_package_";
 pub const ACCESS_KEY_NAME_PREFIX: &str = "cep18_contract_package_access_";
@@ -32,18 +64,126 @@ pub const TRANSFER_ENTRY_POINT_NAME: &str = "transfer";
 pub const APPROVE_ENTRY_POINT_NAME: &str = "approve";
 /// Name of `allowance` entry point.
 pub const ALLOWANCE_ENTRY_POINT_NAME: &str = "allowance";
+/// Name of `allowance_with_expiry` entry point.
+pub const ALLOWANCE_WITH_EXPIRY_ENTRY_POINT_NAME: &str = "allowance_with_expiry";
+/// Name of `permit` entry point.
+pub const PERMIT_ENTRY_POINT_NAME: &str = "permit";
+/// Name of `nonces` entry point.
+pub const NONCES_ENTRY_POINT_NAME: &str = "nonces";
+/// Name of `batch_transfer` entry point.
+pub const BATCH_TRANSFER_ENTRY_POINT_NAME: &str = "batch_transfer";
 /// Name of `transfer_from` entry point.
 pub const TRANSFER_FROM_ENTRY_POINT_NAME: &str = "transfer_from";
 /// Name of `total_supply` entry point.
 pub const TOTAL_SUPPLY_ENTRY_POINT_NAME: &str = "total_supply";
 /// Name of `transfer_from` entry point.
 pub const MINT_ENTRY_POINT_NAME: &str = "mint";
+/// Name of `batch_mint` entry point.
+pub const BATCH_MINT_ENTRY_POINT_NAME: &str = "batch_mint";
 /// Name of `burn` entry point.
 pub const BURN_ENTRY_POINT_NAME: &str = "burn";
+/// Name of `burn_from` entry point.
+pub const BURN_FROM_ENTRY_POINT_NAME: &str = "burn_from";
 /// Name of `init` entry point.
 pub const INIT_ENTRY_POINT_NAME: &str = "init";
+/// Name of `migrate` entry point.
+pub const MIGRATE_ENTRY_POINT_NAME: &str = "migrate";
 /// Name of `change_security` entry point.
 pub const CHANGE_SECURITY_ENTRY_POINT_NAME: &str = "change_security";
+/// Name of `pause` entry point.
+pub const PAUSE_ENTRY_POINT_NAME: &str = "pause";
+/// Name of `unpause` entry point.
+pub const UNPAUSE_ENTRY_POINT_NAME: &str = "unpause";
+/// Name of `freeze_account` entry point.
+pub const FREEZE_ACCOUNT_ENTRY_POINT_NAME: &str = "freeze_account";
+/// Name of `unfreeze_account` entry point.
+pub const UNFREEZE_ACCOUNT_ENTRY_POINT_NAME: &str = "unfreeze_account";
+/// Name of `change_allowlist` entry point.
+pub const CHANGE_ALLOWLIST_ENTRY_POINT_NAME: &str = "change_allowlist";
+/// Name of `max_supply` entry point.
+pub const MAX_SUPPLY_ENTRY_POINT_NAME: &str = "max_supply";
+/// Name of `lower_max_supply` entry point.
+pub const LOWER_MAX_SUPPLY_ENTRY_POINT_NAME: &str = "lower_max_supply";
+/// Name of `grant_role` entry point.
+pub const GRANT_ROLE_ENTRY_POINT_NAME: &str = "grant_role";
+/// Name of `revoke_role` entry point.
+pub const REVOKE_ROLE_ENTRY_POINT_NAME: &str = "revoke_role";
+/// Name of `has_role` entry point.
+pub const HAS_ROLE_ENTRY_POINT_NAME: &str = "has_role";
+/// Name of `renounce_admin` entry point.
+pub const RENOUNCE_ADMIN_ENTRY_POINT_NAME: &str = "renounce_admin";
+/// Name of `propose_admin` entry point.
+pub const PROPOSE_ADMIN_ENTRY_POINT_NAME: &str = "propose_admin";
+/// Name of `accept_admin` entry point.
+pub const ACCEPT_ADMIN_ENTRY_POINT_NAME: &str = "accept_admin";
+/// Name of `cancel_admin_proposal` entry point.
+pub const CANCEL_ADMIN_PROPOSAL_ENTRY_POINT_NAME: &str = "cancel_admin_proposal";
+/// Name of `get_role_members` entry point.
+pub const GET_ROLE_MEMBERS_ENTRY_POINT_NAME: &str = "get_role_members";
+/// Name of `get_admins` entry point.
+pub const GET_ADMINS_ENTRY_POINT_NAME: &str = "get_admins";
+/// Name of `get_minters` entry point.
+pub const GET_MINTERS_ENTRY_POINT_NAME: &str = "get_minters";
+/// Name of `get_badge` entry point.
+pub const GET_BADGE_ENTRY_POINT_NAME: &str = "get_badge";
+/// Name of `index_roles` entry point.
+pub const INDEX_ROLES_ENTRY_POINT_NAME: &str = "index_roles";
+/// Name of `set_minter_allowance` entry point.
+pub const SET_MINTER_ALLOWANCE_ENTRY_POINT_NAME: &str = "set_minter_allowance";
+/// Name of `minter_allowance` entry point.
+pub const MINTER_ALLOWANCE_ENTRY_POINT_NAME: &str = "minter_allowance";
+/// Name of `set_mint_rate_limit` entry point.
+pub const SET_MINT_RATE_LIMIT_ENTRY_POINT_NAME: &str = "set_mint_rate_limit";
+/// Name of `set_fee_rate` entry point.
+pub const SET_FEE_RATE_ENTRY_POINT_NAME: &str = "set_fee_rate";
+/// Name of `set_treasury` entry point.
+pub const SET_TREASURY_ENTRY_POINT_NAME: &str = "set_treasury";
+/// Name of `change_fee_exemption` entry point.
+pub const CHANGE_FEE_EXEMPTION_ENTRY_POINT_NAME: &str = "change_fee_exemption";
+/// Name of `snapshot` entry point.
+pub const SNAPSHOT_ENTRY_POINT_NAME: &str = "snapshot";
+/// Name of `balance_of_at` entry point.
+pub const BALANCE_OF_AT_ENTRY_POINT_NAME: &str = "balance_of_at";
+/// Name of `total_supply_at` entry point.
+pub const TOTAL_SUPPLY_AT_ENTRY_POINT_NAME: &str = "total_supply_at";
+/// Name of `delegate` entry point.
+pub const DELEGATE_ENTRY_POINT_NAME: &str = "delegate";
+/// Name of `delegates` entry point.
+pub const DELEGATES_ENTRY_POINT_NAME: &str = "delegates";
+/// Name of `get_votes` entry point.
+pub const GET_VOTES_ENTRY_POINT_NAME: &str = "get_votes";
+/// Name of `get_past_votes` entry point.
+pub const GET_PAST_VOTES_ENTRY_POINT_NAME: &str = "get_past_votes";
+/// Name of `create_vesting_schedule` entry point.
+pub const CREATE_VESTING_SCHEDULE_ENTRY_POINT_NAME: &str = "create_vesting_schedule";
+/// Name of `locked_balance_of` entry point.
+pub const LOCKED_BALANCE_OF_ENTRY_POINT_NAME: &str = "locked_balance_of";
+/// Name of `vested_amount` entry point.
+pub const VESTED_AMOUNT_ENTRY_POINT_NAME: &str = "vested_amount";
+/// Name of `transfer_and_call` entry point.
+pub const TRANSFER_AND_CALL_ENTRY_POINT_NAME: &str = "transfer_and_call";
+/// Name of `safe_transfer` entry point.
+pub const SAFE_TRANSFER_ENTRY_POINT_NAME: &str = "safe_transfer";
+/// Name of `safe_transfer_from` entry point.
+pub const SAFE_TRANSFER_FROM_ENTRY_POINT_NAME: &str = "safe_transfer_from";
+/// Name of `register_receiver` entry point.
+pub const REGISTER_RECEIVER_ENTRY_POINT_NAME: &str = "register_receiver";
+/// Name of `unregister_receiver` entry point.
+pub const UNREGISTER_RECEIVER_ENTRY_POINT_NAME: &str = "unregister_receiver";
+/// Name of `transfer_with_memo` entry point.
+pub const TRANSFER_WITH_MEMO_ENTRY_POINT_NAME: &str = "transfer_with_memo";
+/// Name of `transfer_from_with_memo` entry point.
+pub const TRANSFER_FROM_WITH_MEMO_ENTRY_POINT_NAME: &str = "transfer_from_with_memo";
+/// Name of `on_cep18_received` entry point of receiver contracts.
+pub const ON_CEP18_RECEIVED_ENTRY_POINT_NAME: &str = "on_cep18_received";
+/// Name of `flash_mint` entry point.
+pub const FLASH_MINT_ENTRY_POINT_NAME: &str = "flash_mint";
+/// Name of `max_flash_loan` entry point.
+pub const MAX_FLASH_LOAN_ENTRY_POINT_NAME: &str = "max_flash_loan";
+/// Name of `flash_fee` entry point.
+pub const FLASH_FEE_ENTRY_POINT_NAME: &str = "flash_fee";
+/// Name of `on_flash_loan` entry point of borrower contracts.
+pub const ON_FLASH_LOAN_ENTRY_POINT_NAME: &str = "on_flash_loan";
 
 pub const INCREASE_ALLOWANCE_ENTRY_POINT_NAME: &str = "increase_allowance";
 pub const DECREASE_ALLOWANCE_ENTRY_POINT_NAME: &str = "decrease_allowance";
@@ -58,6 +198,67 @@ pub const SPENDER: &str = "spender";
 pub const AMOUNT: &str = "amount";
 /// Name of `recipient` runtime argument.
 pub const RECIPIENT: &str = "recipient";
+/// Name of `sender` runtime argument.
+pub const SENDER: &str = "sender";
+/// Name of `data` runtime argument.
+pub const DATA: &str = "data";
+/// Name of `memo` runtime argument.
+pub const MEMO: &str = "memo";
+/// Name of `borrower` runtime argument.
+pub const BORROWER: &str = "borrower";
+/// Name of `initiator` runtime argument.
+pub const INITIATOR: &str = "initiator";
+/// Name of `fee` runtime argument.
+pub const FEE: &str = "fee";
+/// Name of `expires_at` runtime argument.
+pub const EXPIRES_AT: &str = "expires_at";
+/// Name of `mints` runtime argument.
+pub const MINTS: &str = "mints";
+/// Name of `transfers` runtime argument.
+pub const TRANSFERS: &str = "transfers";
+/// Name of `deadline` runtime argument.
+pub const DEADLINE: &str = "deadline";
+/// Name of `nonce` runtime argument.
+pub const NONCE: &str = "nonce";
+/// Name of `signature` runtime argument.
+pub const SIGNATURE: &str = "signature";
+/// Prefix of the message signed for `permit`, keeping permit signatures apart from any other
+/// signed payload.
+pub const PERMIT_MESSAGE_PREFIX: &str = "cep18_permit";
+/// Name of `account` runtime argument.
+pub const ACCOUNT: &str = "account";
+/// Name of `accounts` runtime argument.
+pub const ACCOUNTS: &str = "accounts";
+/// Name of `role` runtime argument.
+pub const ROLE: &str = "role";
+/// Name of `offset` runtime argument.
+pub const OFFSET: &str = "offset";
+/// Name of `limit` runtime argument.
+pub const LIMIT: &str = "limit";
+/// Name of `minter` runtime argument.
+pub const MINTER: &str = "minter";
+/// Name of `allowance` runtime argument.
+pub const ALLOWANCE: &str = "allowance";
+/// Name of `mint_rate_limit` runtime argument and named-key.
+pub const MINT_RATE_LIMIT: &str = "mint_rate_limit";
+/// Name of `mint_rate_window` runtime argument and named-key.
+pub const MINT_RATE_WINDOW: &str = "mint_rate_window";
+/// Name of `snapshot_id` runtime argument.
+pub const SNAPSHOT_ID: &str = "snapshot_id";
+/// Name of `delegatee` runtime argument.
+pub const DELEGATEE: &str = "delegatee";
+/// Name of `blocktime` runtime argument.
+pub const BLOCKTIME: &str = "blocktime";
+/// Name of `holder` runtime argument.
+pub const HOLDER: &str = "holder";
+/// Name of `start` runtime argument.
+pub const START: &str = "start";
+/// Name of `cliff` runtime argument.
+pub const CLIFF: &str = "cliff";
+/// Name of `duration` runtime argument.
+pub const DURATION: &str = "duration";
+/// Name of `total` runtime argument.
+pub const TOTAL: &str = "total";
 pub const PACKAGE_HASH: &str = "package_hash";
 pub const EVENTS_MODE: &str = "events_mode";
 pub const SECURITY_BADGES: &str = "security_badges";
@@ -67,3 +268,33 @@ pub const BURNER_LIST: &str = "burner_list";
 pub const NONE_LIST: &str = "none_list";
 pub const MINT_AND_BURN_LIST: &str = "mint_and_burn_list";
 pub const ENABLE_MINT_BURN: &str = "enable_mint_burn";
+pub const PAUSER_LIST: &str = "pauser_list";
+pub const ENABLE_PAUSE: &str = "enable_pause";
+/// Name of named-key for `paused`
+pub const PAUSED: &str = "paused";
+/// Name of named-key for `admin_count`
+pub const ADMIN_COUNT: &str = "admin_count";
+pub const ENABLE_FREEZE: &str = "enable_freeze";
+pub const TRANSFER_RESTRICTION: &str = "transfer_restriction";
+pub const BATCH_TRANSFER_EVENTS: &str = "batch_transfer_events";
+pub const INITIAL_BALANCES: &str = "initial_balances";
+pub const ALLOWLISTER_LIST: &str = "allowlister_list";
+pub const ALLOW_LIST: &str = "allow_list";
+pub const DISALLOW_LIST: &str = "disallow_list";
+pub const TRANSFER_FEE: &str = "transfer_fee";
+/// Name of `fee_rate` runtime argument and named-key.
+pub const FEE_RATE: &str = "fee_rate";
+/// Name of `max_fee_rate` runtime argument and named-key.
+pub const MAX_FEE_RATE: &str = "max_fee_rate";
+/// Name of `treasury` runtime argument and named-key.
+pub const TREASURY: &str = "treasury";
+/// Name of dictionary-key for `fee_exempt`
+pub const FEE_EXEMPT: &str = "fee_exempt";
+/// Name of dictionary-key for `registered_receivers`
+pub const REGISTERED_RECEIVERS: &str = "registered_receivers";
+pub const FEE_EXEMPT_LIST: &str = "fee_exempt_list";
+pub const FEE_NON_EXEMPT_LIST: &str = "fee_non_exempt_list";
+pub const ENABLE_FLASH_MINT: &str = "enable_flash_mint";
+/// Name of `flash_fee_rate` runtime argument and named-key.
+pub const FLASH_FEE_RATE: &str = "flash_fee_rate";
+pub const ENABLE_VESTING: &str = "enable_vesting";
@@ -2,17 +2,41 @@
 use alloc::{string::String, vec, vec::Vec};
 
 use casper_types::{
-    CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Key, Parameter,
-    U256,
+    bytesrepr::Bytes, CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints,
+    Key, Parameter, PublicKey, U256,
 };
 
 use crate::constants::{
-    ADDRESS, ALLOWANCE_ENTRY_POINT_NAME, AMOUNT, APPROVE_ENTRY_POINT_NAME,
-    BALANCE_OF_ENTRY_POINT_NAME, BURN_ENTRY_POINT_NAME, CHANGE_SECURITY_ENTRY_POINT_NAME,
-    DECIMALS_ENTRY_POINT_NAME, DECREASE_ALLOWANCE_ENTRY_POINT_NAME,
-    INCREASE_ALLOWANCE_ENTRY_POINT_NAME, INIT_ENTRY_POINT_NAME, MINT_ENTRY_POINT_NAME,
-    NAME_ENTRY_POINT_NAME, OWNER, RECIPIENT, SPENDER, SYMBOL_ENTRY_POINT_NAME,
-    TOTAL_SUPPLY_ENTRY_POINT_NAME, TRANSFER_ENTRY_POINT_NAME, TRANSFER_FROM_ENTRY_POINT_NAME,
+    ACCEPT_ADMIN_ENTRY_POINT_NAME, ACCOUNT, ADDRESS, ALLOWANCE, ALLOWANCE_ENTRY_POINT_NAME,
+    ALLOWANCE_WITH_EXPIRY_ENTRY_POINT_NAME, AMOUNT, APPROVE_ENTRY_POINT_NAME,
+    BALANCE_OF_AT_ENTRY_POINT_NAME, BALANCE_OF_ENTRY_POINT_NAME, BATCH_MINT_ENTRY_POINT_NAME,
+    BATCH_TRANSFER_ENTRY_POINT_NAME, BLOCKTIME, BORROWER, BURN_ENTRY_POINT_NAME,
+    BURN_FROM_ENTRY_POINT_NAME, CANCEL_ADMIN_PROPOSAL_ENTRY_POINT_NAME,
+    CHANGE_ALLOWLIST_ENTRY_POINT_NAME, CHANGE_FEE_EXEMPTION_ENTRY_POINT_NAME,
+    CHANGE_SECURITY_ENTRY_POINT_NAME, CLIFF, CREATE_VESTING_SCHEDULE_ENTRY_POINT_NAME, DATA,
+    DEADLINE, DECIMALS_ENTRY_POINT_NAME, DECREASE_ALLOWANCE_ENTRY_POINT_NAME, DELEGATEE,
+    DELEGATES_ENTRY_POINT_NAME, DELEGATE_ENTRY_POINT_NAME, DURATION, FEE_RATE,
+    FLASH_FEE_ENTRY_POINT_NAME, FLASH_MINT_ENTRY_POINT_NAME, FREEZE_ACCOUNT_ENTRY_POINT_NAME,
+    GET_ADMINS_ENTRY_POINT_NAME, GET_BADGE_ENTRY_POINT_NAME, GET_MINTERS_ENTRY_POINT_NAME,
+    GET_PAST_VOTES_ENTRY_POINT_NAME, GET_ROLE_MEMBERS_ENTRY_POINT_NAME, GET_VOTES_ENTRY_POINT_NAME,
+    GRANT_ROLE_ENTRY_POINT_NAME, HAS_ROLE_ENTRY_POINT_NAME, HOLDER,
+    INCREASE_ALLOWANCE_ENTRY_POINT_NAME, INDEX_ROLES_ENTRY_POINT_NAME, INIT_ENTRY_POINT_NAME,
+    LIMIT, LOCKED_BALANCE_OF_ENTRY_POINT_NAME, LOWER_MAX_SUPPLY_ENTRY_POINT_NAME,
+    MAX_FLASH_LOAN_ENTRY_POINT_NAME, MAX_SUPPLY, MAX_SUPPLY_ENTRY_POINT_NAME, MEMO,
+    MIGRATE_ENTRY_POINT_NAME, MINTER, MINTER_ALLOWANCE_ENTRY_POINT_NAME, MINTS,
+    MINT_ENTRY_POINT_NAME, MINT_RATE_LIMIT, MINT_RATE_WINDOW, NAME_ENTRY_POINT_NAME, NONCE,
+    NONCES_ENTRY_POINT_NAME, OFFSET, OWNER, PAUSE_ENTRY_POINT_NAME, PERMIT_ENTRY_POINT_NAME,
+    PROPOSE_ADMIN_ENTRY_POINT_NAME, RECIPIENT, REGISTER_RECEIVER_ENTRY_POINT_NAME,
+    RENOUNCE_ADMIN_ENTRY_POINT_NAME, REVOKE_ROLE_ENTRY_POINT_NAME, ROLE,
+    SAFE_TRANSFER_ENTRY_POINT_NAME, SAFE_TRANSFER_FROM_ENTRY_POINT_NAME,
+    SET_FEE_RATE_ENTRY_POINT_NAME, SET_MINTER_ALLOWANCE_ENTRY_POINT_NAME,
+    SET_MINT_RATE_LIMIT_ENTRY_POINT_NAME, SET_TREASURY_ENTRY_POINT_NAME, SIGNATURE,
+    SNAPSHOT_ENTRY_POINT_NAME, SNAPSHOT_ID, SPENDER, START, SYMBOL_ENTRY_POINT_NAME, TOTAL,
+    TOTAL_SUPPLY_AT_ENTRY_POINT_NAME, TOTAL_SUPPLY_ENTRY_POINT_NAME, TRANSFERS,
+    TRANSFER_AND_CALL_ENTRY_POINT_NAME, TRANSFER_ENTRY_POINT_NAME, TRANSFER_FROM_ENTRY_POINT_NAME,
+    TRANSFER_FROM_WITH_MEMO_ENTRY_POINT_NAME, TRANSFER_WITH_MEMO_ENTRY_POINT_NAME, TREASURY,
+    UNFREEZE_ACCOUNT_ENTRY_POINT_NAME, UNPAUSE_ENTRY_POINT_NAME,
+    UNREGISTER_RECEIVER_ENTRY_POINT_NAME, VESTED_AMOUNT_ENTRY_POINT_NAME,
 };
 
 /// Returns the `name` entry point.
@@ -37,6 +61,17 @@ pub fn symbol() -> EntryPoint {
     )
 }
 
+/// Returns the `batch_transfer` entry point.
+pub fn batch_transfer() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BATCH_TRANSFER_ENTRY_POINT_NAME),
+        vec![Parameter::new(TRANSFERS, Vec::<(Key, U256)>::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 /// Returns the `transfer_from` entry point.
 pub fn transfer_from() -> EntryPoint {
     EntryPoint::new(
@@ -66,6 +101,20 @@ pub fn allowance() -> EntryPoint {
     )
 }
 
+/// Returns the `allowance_with_expiry` entry point.
+pub fn allowance_with_expiry() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ALLOWANCE_WITH_EXPIRY_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OWNER, Key::cl_type()),
+            Parameter::new(SPENDER, Key::cl_type()),
+        ],
+        <(U256, Option<u64>)>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 /// Returns the `approve` entry point.
 pub fn approve() -> EntryPoint {
     EntryPoint::new(
@@ -73,6 +122,10 @@ pub fn approve() -> EntryPoint {
         vec![
             Parameter::new(SPENDER, Key::cl_type()),
             Parameter::new(AMOUNT, U256::cl_type()),
+            // Optional Arguments (can be added or omitted when calling):
+            /*
+            - "expires_at" : u64
+            */
         ],
         CLType::Unit,
         EntryPointAccess::Public,
@@ -87,6 +140,10 @@ pub fn increase_allowance() -> EntryPoint {
         vec![
             Parameter::new(SPENDER, Key::cl_type()),
             Parameter::new(AMOUNT, U256::cl_type()),
+            // Optional Arguments (can be added or omitted when calling):
+            /*
+            - "expires_at" : u64
+            */
         ],
         CLType::Unit,
         EntryPointAccess::Public,
@@ -108,6 +165,35 @@ pub fn decrease_allowance() -> EntryPoint {
     )
 }
 
+/// Returns the `permit` entry point.
+pub fn permit() -> EntryPoint {
+    EntryPoint::new(
+        String::from(PERMIT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OWNER, PublicKey::cl_type()),
+            Parameter::new(SPENDER, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(DEADLINE, u64::cl_type()),
+            Parameter::new(NONCE, u64::cl_type()),
+            Parameter::new(SIGNATURE, Bytes::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `nonces` entry point.
+pub fn nonces() -> EntryPoint {
+    EntryPoint::new(
+        String::from(NONCES_ENTRY_POINT_NAME),
+        vec![Parameter::new(OWNER, Key::cl_type())],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 /// Returns the `transfer` entry point.
 pub fn transfer() -> EntryPoint {
     EntryPoint::new(
@@ -144,6 +230,17 @@ pub fn total_supply() -> EntryPoint {
     )
 }
 
+/// Returns the `max_supply` entry point.
+pub fn max_supply() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MAX_SUPPLY_ENTRY_POINT_NAME),
+        Vec::new(),
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 /// Returns the `decimals` entry point.
 pub fn decimals() -> EntryPoint {
     EntryPoint::new(
@@ -155,6 +252,17 @@ pub fn decimals() -> EntryPoint {
     )
 }
 
+/// Returns the `batch_mint` entry point.
+pub fn batch_mint() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BATCH_MINT_ENTRY_POINT_NAME),
+        vec![Parameter::new(MINTS, Vec::<(Key, U256)>::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 /// Returns the `burn` entry point.
 pub fn burn() -> EntryPoint {
     EntryPoint::new(
@@ -169,6 +277,20 @@ pub fn burn() -> EntryPoint {
     )
 }
 
+/// Returns the `burn_from` entry point.
+pub fn burn_from() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BURN_FROM_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OWNER, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 /// Returns the `mint` entry point.
 pub fn mint() -> EntryPoint {
     EntryPoint::new(
@@ -194,6 +316,8 @@ pub fn change_security() -> EntryPoint {
             - "mint_and_burn_list" : Vec<Key>
             - "minter_list" : Vec<Key>
             - "burner_list" : Vec<Key>
+            - "pauser_list" : Vec<Key>
+            - "allowlister_list" : Vec<Key>
             - "none_list" : Vec<Key>
             */
         ],
@@ -203,6 +327,557 @@ pub fn change_security() -> EntryPoint {
     )
 }
 
+/// Returns the `grant_role` entry point.
+pub fn grant_role() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GRANT_ROLE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ACCOUNT, Key::cl_type()),
+            Parameter::new(ROLE, u8::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `revoke_role` entry point.
+pub fn revoke_role() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REVOKE_ROLE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ACCOUNT, Key::cl_type()),
+            Parameter::new(ROLE, u8::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `renounce_admin` entry point.
+pub fn renounce_admin() -> EntryPoint {
+    EntryPoint::new(
+        String::from(RENOUNCE_ADMIN_ENTRY_POINT_NAME),
+        Vec::new(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `propose_admin` entry point.
+pub fn propose_admin() -> EntryPoint {
+    EntryPoint::new(
+        String::from(PROPOSE_ADMIN_ENTRY_POINT_NAME),
+        vec![Parameter::new(ACCOUNT, Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `accept_admin` entry point.
+pub fn accept_admin() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ACCEPT_ADMIN_ENTRY_POINT_NAME),
+        Vec::new(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `cancel_admin_proposal` entry point.
+pub fn cancel_admin_proposal() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CANCEL_ADMIN_PROPOSAL_ENTRY_POINT_NAME),
+        vec![Parameter::new(ACCOUNT, Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `get_role_members` entry point.
+pub fn get_role_members() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GET_ROLE_MEMBERS_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ROLE, u8::cl_type()),
+            Parameter::new(OFFSET, u32::cl_type()),
+            Parameter::new(LIMIT, u32::cl_type()),
+        ],
+        Vec::<Key>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `get_admins` entry point.
+pub fn get_admins() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GET_ADMINS_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OFFSET, u32::cl_type()),
+            Parameter::new(LIMIT, u32::cl_type()),
+        ],
+        Vec::<Key>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `get_minters` entry point.
+pub fn get_minters() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GET_MINTERS_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OFFSET, u32::cl_type()),
+            Parameter::new(LIMIT, u32::cl_type()),
+        ],
+        Vec::<Key>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `get_badge` entry point.
+pub fn get_badge() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GET_BADGE_ENTRY_POINT_NAME),
+        vec![
+            // Optional Arguments (can be added or omitted when calling):
+            /*
+            - "account" : Key
+            */
+        ],
+        u8::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `has_role` entry point.
+pub fn has_role() -> EntryPoint {
+    EntryPoint::new(
+        String::from(HAS_ROLE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ACCOUNT, Key::cl_type()),
+            Parameter::new(ROLE, u8::cl_type()),
+        ],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_minter_allowance` entry point.
+pub fn set_minter_allowance() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_MINTER_ALLOWANCE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(MINTER, Key::cl_type()),
+            Parameter::new(ALLOWANCE, Option::<U256>::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `minter_allowance` entry point.
+pub fn minter_allowance() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MINTER_ALLOWANCE_ENTRY_POINT_NAME),
+        vec![Parameter::new(MINTER, Key::cl_type())],
+        Option::<U256>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_mint_rate_limit` entry point.
+pub fn set_mint_rate_limit() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_MINT_RATE_LIMIT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(MINT_RATE_LIMIT, U256::cl_type()),
+            Parameter::new(MINT_RATE_WINDOW, u64::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `pause` entry point.
+pub fn pause() -> EntryPoint {
+    EntryPoint::new(
+        String::from(PAUSE_ENTRY_POINT_NAME),
+        Vec::new(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `unpause` entry point.
+pub fn unpause() -> EntryPoint {
+    EntryPoint::new(
+        String::from(UNPAUSE_ENTRY_POINT_NAME),
+        Vec::new(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `freeze_account` entry point.
+pub fn freeze_account() -> EntryPoint {
+    EntryPoint::new(
+        String::from(FREEZE_ACCOUNT_ENTRY_POINT_NAME),
+        vec![Parameter::new(ACCOUNT, Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `unfreeze_account` entry point.
+pub fn unfreeze_account() -> EntryPoint {
+    EntryPoint::new(
+        String::from(UNFREEZE_ACCOUNT_ENTRY_POINT_NAME),
+        vec![Parameter::new(ACCOUNT, Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `change_allowlist` entry point.
+pub fn change_allowlist() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CHANGE_ALLOWLIST_ENTRY_POINT_NAME),
+        vec![
+            // Optional Arguments (can be added or omitted when calling):
+            /*
+            - "allow_list" : Vec<Key>
+            - "disallow_list" : Vec<Key>
+            */
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_fee_rate` entry point.
+pub fn set_fee_rate() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_FEE_RATE_ENTRY_POINT_NAME),
+        vec![Parameter::new(FEE_RATE, u32::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_treasury` entry point.
+pub fn set_treasury() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_TREASURY_ENTRY_POINT_NAME),
+        vec![Parameter::new(TREASURY, Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `change_fee_exemption` entry point.
+pub fn change_fee_exemption() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CHANGE_FEE_EXEMPTION_ENTRY_POINT_NAME),
+        vec![
+            // Optional Arguments (can be added or omitted when calling):
+            /*
+            - "fee_exempt_list" : Vec<Key>
+            - "fee_non_exempt_list" : Vec<Key>
+            */
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `snapshot` entry point.
+pub fn snapshot() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SNAPSHOT_ENTRY_POINT_NAME),
+        Vec::new(),
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `balance_of_at` entry point.
+pub fn balance_of_at() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BALANCE_OF_AT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ADDRESS, Key::cl_type()),
+            Parameter::new(SNAPSHOT_ID, u64::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `total_supply_at` entry point.
+pub fn total_supply_at() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TOTAL_SUPPLY_AT_ENTRY_POINT_NAME),
+        vec![Parameter::new(SNAPSHOT_ID, u64::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `delegate` entry point.
+pub fn delegate() -> EntryPoint {
+    EntryPoint::new(
+        String::from(DELEGATE_ENTRY_POINT_NAME),
+        vec![Parameter::new(DELEGATEE, Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `delegates` entry point.
+pub fn delegates() -> EntryPoint {
+    EntryPoint::new(
+        String::from(DELEGATES_ENTRY_POINT_NAME),
+        vec![Parameter::new(ACCOUNT, Key::cl_type())],
+        Option::<Key>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `get_votes` entry point.
+pub fn get_votes() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GET_VOTES_ENTRY_POINT_NAME),
+        vec![Parameter::new(ACCOUNT, Key::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `get_past_votes` entry point.
+pub fn get_past_votes() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GET_PAST_VOTES_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ACCOUNT, Key::cl_type()),
+            Parameter::new(BLOCKTIME, u64::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `create_vesting_schedule` entry point.
+pub fn create_vesting_schedule() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CREATE_VESTING_SCHEDULE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(HOLDER, Key::cl_type()),
+            Parameter::new(START, u64::cl_type()),
+            Parameter::new(CLIFF, u64::cl_type()),
+            Parameter::new(DURATION, u64::cl_type()),
+            Parameter::new(TOTAL, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `locked_balance_of` entry point.
+pub fn locked_balance_of() -> EntryPoint {
+    EntryPoint::new(
+        String::from(LOCKED_BALANCE_OF_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESS, Key::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `vested_amount` entry point.
+pub fn vested_amount() -> EntryPoint {
+    EntryPoint::new(
+        String::from(VESTED_AMOUNT_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESS, Key::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `transfer_and_call` entry point.
+pub fn transfer_and_call() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TRANSFER_AND_CALL_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(RECIPIENT, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(DATA, Bytes::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `safe_transfer` entry point.
+pub fn safe_transfer() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SAFE_TRANSFER_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(RECIPIENT, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `register_receiver` entry point.
+pub fn register_receiver() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REGISTER_RECEIVER_ENTRY_POINT_NAME),
+        Vec::new(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `unregister_receiver` entry point.
+pub fn unregister_receiver() -> EntryPoint {
+    EntryPoint::new(
+        String::from(UNREGISTER_RECEIVER_ENTRY_POINT_NAME),
+        Vec::new(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `safe_transfer_from` entry point.
+pub fn safe_transfer_from() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SAFE_TRANSFER_FROM_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OWNER, Key::cl_type()),
+            Parameter::new(RECIPIENT, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `transfer_with_memo` entry point.
+pub fn transfer_with_memo() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TRANSFER_WITH_MEMO_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(RECIPIENT, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(MEMO, u64::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `transfer_from_with_memo` entry point.
+pub fn transfer_from_with_memo() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TRANSFER_FROM_WITH_MEMO_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OWNER, Key::cl_type()),
+            Parameter::new(RECIPIENT, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(MEMO, u64::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `flash_mint` entry point.
+pub fn flash_mint() -> EntryPoint {
+    EntryPoint::new(
+        String::from(FLASH_MINT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(BORROWER, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(DATA, Bytes::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `max_flash_loan` entry point.
+pub fn max_flash_loan() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MAX_FLASH_LOAN_ENTRY_POINT_NAME),
+        Vec::new(),
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `flash_fee` entry point.
+pub fn flash_fee() -> EntryPoint {
+    EntryPoint::new(
+        String::from(FLASH_FEE_ENTRY_POINT_NAME),
+        vec![Parameter::new(AMOUNT, U256::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `lower_max_supply` entry point.
+pub fn lower_max_supply() -> EntryPoint {
+    EntryPoint::new(
+        String::from(LOWER_MAX_SUPPLY_ENTRY_POINT_NAME),
+        vec![Parameter::new(MAX_SUPPLY, U256::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 /// Returns the `init` entry point.
 pub fn init() -> EntryPoint {
     EntryPoint::new(
@@ -214,23 +889,97 @@ pub fn init() -> EntryPoint {
     )
 }
 
+/// Returns the `migrate` entry point.
+pub fn migrate() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MIGRATE_ENTRY_POINT_NAME),
+        Vec::new(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `index_roles` entry point.
+pub fn index_roles() -> EntryPoint {
+    EntryPoint::new(
+        String::from(INDEX_ROLES_ENTRY_POINT_NAME),
+        vec![Parameter::new(ACCOUNTS, Vec::<Key>::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 /// Returns the default set of CEP-18 token entry points.
 pub fn generate_entry_points() -> EntryPoints {
     let mut entry_points = EntryPoints::new();
     entry_points.add_entry_point(init());
+    entry_points.add_entry_point(migrate());
     entry_points.add_entry_point(name());
     entry_points.add_entry_point(symbol());
     entry_points.add_entry_point(decimals());
     entry_points.add_entry_point(total_supply());
+    entry_points.add_entry_point(max_supply());
     entry_points.add_entry_point(balance_of());
     entry_points.add_entry_point(transfer());
+    entry_points.add_entry_point(batch_transfer());
     entry_points.add_entry_point(approve());
     entry_points.add_entry_point(allowance());
+    entry_points.add_entry_point(allowance_with_expiry());
     entry_points.add_entry_point(decrease_allowance());
     entry_points.add_entry_point(increase_allowance());
+    entry_points.add_entry_point(permit());
+    entry_points.add_entry_point(nonces());
     entry_points.add_entry_point(transfer_from());
     entry_points.add_entry_point(change_security());
+    entry_points.add_entry_point(grant_role());
+    entry_points.add_entry_point(revoke_role());
+    entry_points.add_entry_point(renounce_admin());
+    entry_points.add_entry_point(propose_admin());
+    entry_points.add_entry_point(accept_admin());
+    entry_points.add_entry_point(cancel_admin_proposal());
+    entry_points.add_entry_point(has_role());
+    entry_points.add_entry_point(get_role_members());
+    entry_points.add_entry_point(get_admins());
+    entry_points.add_entry_point(get_minters());
+    entry_points.add_entry_point(get_badge());
+    entry_points.add_entry_point(index_roles());
     entry_points.add_entry_point(burn());
+    entry_points.add_entry_point(burn_from());
     entry_points.add_entry_point(mint());
+    entry_points.add_entry_point(batch_mint());
+    entry_points.add_entry_point(set_minter_allowance());
+    entry_points.add_entry_point(minter_allowance());
+    entry_points.add_entry_point(set_mint_rate_limit());
+    entry_points.add_entry_point(pause());
+    entry_points.add_entry_point(unpause());
+    entry_points.add_entry_point(freeze_account());
+    entry_points.add_entry_point(unfreeze_account());
+    entry_points.add_entry_point(change_allowlist());
+    entry_points.add_entry_point(lower_max_supply());
+    entry_points.add_entry_point(set_fee_rate());
+    entry_points.add_entry_point(set_treasury());
+    entry_points.add_entry_point(change_fee_exemption());
+    entry_points.add_entry_point(snapshot());
+    entry_points.add_entry_point(balance_of_at());
+    entry_points.add_entry_point(total_supply_at());
+    entry_points.add_entry_point(delegate());
+    entry_points.add_entry_point(delegates());
+    entry_points.add_entry_point(get_votes());
+    entry_points.add_entry_point(get_past_votes());
+    entry_points.add_entry_point(create_vesting_schedule());
+    entry_points.add_entry_point(locked_balance_of());
+    entry_points.add_entry_point(vested_amount());
+    entry_points.add_entry_point(transfer_and_call());
+    entry_points.add_entry_point(safe_transfer());
+    entry_points.add_entry_point(safe_transfer_from());
+    entry_points.add_entry_point(register_receiver());
+    entry_points.add_entry_point(unregister_receiver());
+    entry_points.add_entry_point(transfer_with_memo());
+    entry_points.add_entry_point(transfer_from_with_memo());
+    entry_points.add_entry_point(flash_mint());
+    entry_points.add_entry_point(max_flash_loan());
+    entry_points.add_entry_point(flash_fee());
     entry_points
 }
@@ -1,7 +1,10 @@
 use core::convert::TryFrom;
 
-use alloc::collections::BTreeMap;
-use casper_contract::unwrap_or_revert::UnwrapOrRevert;
+use alloc::{collections::BTreeMap, vec::Vec};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
 use casper_types::{Key, U256};
 
 use crate::{
@@ -10,7 +13,7 @@ use crate::{
     utils::{read_from, SecurityBadge},
 };
 
-use casper_event_standard::{emit, Event, Schemas};
+use casper_event_standard::{emit, Event, Schemas, EVENTS_SCHEMA};
 
 pub fn record_event_dictionary(event: Event) {
     let events_mode: EventsMode =
@@ -31,6 +34,30 @@ pub enum Event {
     Transfer(Transfer),
     TransferFrom(TransferFrom),
     ChangeSecurity(ChangeSecurity),
+    Paused(Paused),
+    Unpaused(Unpaused),
+    Frozen(Frozen),
+    Unfrozen(Unfrozen),
+    ChangeAllowlist(ChangeAllowlist),
+    LowerMaxSupply(LowerMaxSupply),
+    BatchTransfer(BatchTransfer),
+    RoleGranted(RoleGranted),
+    RoleRevoked(RoleRevoked),
+    AdminProposed(AdminProposed),
+    AdminAccepted(AdminAccepted),
+    AdminProposalCancelled(AdminProposalCancelled),
+    MinterAllowanceChanged(MinterAllowanceChanged),
+    MintRateLimitChanged(MintRateLimitChanged),
+    FeeCharged(FeeCharged),
+    FeeRateChanged(FeeRateChanged),
+    TreasuryChanged(TreasuryChanged),
+    ChangeFeeExemption(ChangeFeeExemption),
+    Snapshot(Snapshot),
+    DelegateChanged(DelegateChanged),
+    DelegateVotesChanged(DelegateVotesChanged),
+    VestingScheduleCreated(VestingScheduleCreated),
+    TransferWithMemo(TransferWithMemo),
+    FlashMint(FlashMint),
 }
 
 #[derive(Event, Debug, PartialEq, Eq)]
@@ -43,6 +70,7 @@ pub struct Mint {
 pub struct Burn {
     pub owner: Key,
     pub amount: U256,
+    pub spender: Option<Key>,
 }
 
 #[derive(Event, Debug, PartialEq, Eq)]
@@ -50,6 +78,7 @@ pub struct SetAllowance {
     pub owner: Key,
     pub spender: Key,
     pub allowance: U256,
+    pub expires_at: Option<u64>,
 }
 
 #[derive(Event, Debug, PartialEq, Eq)]
@@ -58,6 +87,7 @@ pub struct IncreaseAllowance {
     pub spender: Key,
     pub allowance: U256,
     pub inc_by: U256,
+    pub expires_at: Option<u64>,
 }
 
 #[derive(Event, Debug, PartialEq, Eq)]
@@ -89,6 +119,163 @@ pub struct ChangeSecurity {
     pub sec_change_map: BTreeMap<Key, SecurityBadge>,
 }
 
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Paused {
+    pub pauser: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Unpaused {
+    pub pauser: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Frozen {
+    pub admin: Key,
+    pub account: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Unfrozen {
+    pub admin: Key,
+    pub account: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ChangeAllowlist {
+    pub manager: Key,
+    pub allowlist_change_map: BTreeMap<Key, bool>,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct LowerMaxSupply {
+    pub admin: Key,
+    pub max_supply: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct BatchTransfer {
+    pub sender: Key,
+    pub transfers: Vec<(Key, U256)>,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct RoleGranted {
+    pub admin: Key,
+    pub account: Key,
+    pub role: SecurityBadge,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct RoleRevoked {
+    pub admin: Key,
+    pub account: Key,
+    pub role: SecurityBadge,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct AdminProposed {
+    pub proposer: Key,
+    pub nominee: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct AdminAccepted {
+    pub proposer: Key,
+    pub nominee: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct AdminProposalCancelled {
+    pub proposer: Key,
+    pub nominee: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct MinterAllowanceChanged {
+    pub admin: Key,
+    pub minter: Key,
+    pub allowance: Option<U256>,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct MintRateLimitChanged {
+    pub admin: Key,
+    pub limit: U256,
+    pub window: u64,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct FeeCharged {
+    pub payer: Key,
+    pub treasury: Key,
+    pub fee: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct FeeRateChanged {
+    pub admin: Key,
+    pub fee_rate: u32,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct TreasuryChanged {
+    pub admin: Key,
+    pub treasury: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ChangeFeeExemption {
+    pub admin: Key,
+    pub fee_exemption_change_map: BTreeMap<Key, bool>,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Snapshot {
+    pub admin: Key,
+    pub snapshot_id: u64,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct DelegateChanged {
+    pub delegator: Key,
+    pub from_delegate: Option<Key>,
+    pub to_delegate: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct DelegateVotesChanged {
+    pub delegate: Key,
+    pub previous_votes: U256,
+    pub new_votes: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct VestingScheduleCreated {
+    pub admin: Key,
+    pub holder: Key,
+    pub start: u64,
+    pub cliff: u64,
+    pub duration: u64,
+    pub total: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct TransferWithMemo {
+    pub sender: Key,
+    pub recipient: Key,
+    pub amount: U256,
+    pub memo: u64,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct FlashMint {
+    pub initiator: Key,
+    pub borrower: Key,
+    pub amount: U256,
+    pub fee: U256,
+}
+
 fn ces(event: Event) {
     match event {
         Event::Mint(ev) => emit(ev),
@@ -99,23 +286,89 @@ fn ces(event: Event) {
         Event::Transfer(ev) => emit(ev),
         Event::TransferFrom(ev) => emit(ev),
         Event::ChangeSecurity(ev) => emit(ev),
+        Event::Paused(ev) => emit(ev),
+        Event::Unpaused(ev) => emit(ev),
+        Event::Frozen(ev) => emit(ev),
+        Event::Unfrozen(ev) => emit(ev),
+        Event::ChangeAllowlist(ev) => emit(ev),
+        Event::LowerMaxSupply(ev) => emit(ev),
+        Event::BatchTransfer(ev) => emit(ev),
+        Event::RoleGranted(ev) => emit(ev),
+        Event::RoleRevoked(ev) => emit(ev),
+        Event::AdminProposed(ev) => emit(ev),
+        Event::AdminAccepted(ev) => emit(ev),
+        Event::AdminProposalCancelled(ev) => emit(ev),
+        Event::MinterAllowanceChanged(ev) => emit(ev),
+        Event::MintRateLimitChanged(ev) => emit(ev),
+        Event::FeeCharged(ev) => emit(ev),
+        Event::FeeRateChanged(ev) => emit(ev),
+        Event::TreasuryChanged(ev) => emit(ev),
+        Event::ChangeFeeExemption(ev) => emit(ev),
+        Event::Snapshot(ev) => emit(ev),
+        Event::DelegateChanged(ev) => emit(ev),
+        Event::DelegateVotesChanged(ev) => emit(ev),
+        Event::VestingScheduleCreated(ev) => emit(ev),
+        Event::TransferWithMemo(ev) => emit(ev),
+        Event::FlashMint(ev) => emit(ev),
     }
 }
 
+/// Returns the schemas of all the events the contract emits.
+fn schemas() -> Schemas {
+    Schemas::new()
+        .with::<Mint>()
+        .with::<Burn>()
+        .with::<SetAllowance>()
+        .with::<IncreaseAllowance>()
+        .with::<DecreaseAllowance>()
+        .with::<Transfer>()
+        .with::<TransferFrom>()
+        .with::<ChangeSecurity>()
+        .with::<Paused>()
+        .with::<Unpaused>()
+        .with::<Frozen>()
+        .with::<Unfrozen>()
+        .with::<ChangeAllowlist>()
+        .with::<LowerMaxSupply>()
+        .with::<BatchTransfer>()
+        .with::<RoleGranted>()
+        .with::<RoleRevoked>()
+        .with::<AdminProposed>()
+        .with::<AdminAccepted>()
+        .with::<AdminProposalCancelled>()
+        .with::<MinterAllowanceChanged>()
+        .with::<MintRateLimitChanged>()
+        .with::<FeeCharged>()
+        .with::<FeeRateChanged>()
+        .with::<TreasuryChanged>()
+        .with::<ChangeFeeExemption>()
+        .with::<Snapshot>()
+        .with::<DelegateChanged>()
+        .with::<DelegateVotesChanged>()
+        .with::<VestingScheduleCreated>()
+        .with::<TransferWithMemo>()
+        .with::<FlashMint>()
+}
+
 pub fn init_events() {
     let events_mode: EventsMode =
         EventsMode::try_from(read_from::<u8>(EVENTS_MODE)).unwrap_or_revert();
 
     if events_mode == EventsMode::CES {
-        let schemas = Schemas::new()
-            .with::<Mint>()
-            .with::<Burn>()
-            .with::<SetAllowance>()
-            .with::<IncreaseAllowance>()
-            .with::<DecreaseAllowance>()
-            .with::<Transfer>()
-            .with::<TransferFrom>()
-            .with::<ChangeSecurity>();
-        casper_event_standard::init(schemas);
+        casper_event_standard::init(schemas());
+    }
+}
+
+/// Overwrites the stored event schemas of an upgraded contract, since events may have been added
+/// or gained fields since the contract was installed.
+pub fn upgrade_events_schema() {
+    let events_mode: EventsMode =
+        EventsMode::try_from(read_from::<u8>(EVENTS_MODE)).unwrap_or_revert();
+
+    if events_mode == EventsMode::CES {
+        let schemas_uref = runtime::get_key(EVENTS_SCHEMA)
+            .and_then(Key::into_uref)
+            .unwrap_or_revert();
+        storage::write(schemas_uref, schemas());
     }
 }
@@ -0,0 +1,75 @@
+//! Implementation of flash mints.
+//!
+//! A flash mint lends freshly minted tokens to a borrower contract for the duration of a single
+//! execution. Borrower contracts expose a public `on_flash_loan` entry point taking the
+//! `initiator` of the loan (`Key`), the `amount` lent (`U256`), the `fee` due (`U256`) and the
+//! `data` (`Bytes`) passed along, and returning whether they accept the loan (`bool`). The amount
+//! and the fee are burned from the borrower once the hook returns.
+use core::convert::TryFrom;
+
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{bytesrepr::Bytes, runtime_args, ContractPackageHash, Key, RuntimeArgs, U256};
+
+use crate::{
+    constants::{
+        AMOUNT, DATA, ENABLE_FLASH_MINT, FEE, FLASH_FEE_RATE, INITIATOR,
+        ON_FLASH_LOAN_ENTRY_POINT_NAME,
+    },
+    error::Cep18Error,
+    modalities::FlashMint,
+    transfer_fee::apply_basis_points,
+    utils,
+};
+
+/// Returns `true` if the `Enabled` flash mint modality was selected at install.
+pub(crate) fn flash_mint_enabled() -> bool {
+    let enable_flash_mint = utils::read_optional_from::<u8>(ENABLE_FLASH_MINT).unwrap_or_default();
+    FlashMint::try_from(enable_flash_mint).unwrap_or_revert() == FlashMint::Enabled
+}
+
+/// Reads the maximum amount that can be flash minted: the room left under the maximum supply, or
+/// `0` when flash mints are disabled.
+pub(crate) fn read_max_flash_loan() -> U256 {
+    if !flash_mint_enabled() {
+        return U256::zero();
+    }
+    let total_supply = utils::read_total_supply_from(utils::get_total_supply_uref());
+    utils::read_max_supply().saturating_sub(total_supply)
+}
+
+/// Returns the fee charged on a flash mint of `amount`, rounded down.
+pub(crate) fn compute_flash_fee(amount: U256) -> U256 {
+    let flash_fee_rate = utils::read_optional_from::<u32>(FLASH_FEE_RATE).unwrap_or_default();
+    if flash_fee_rate == 0 {
+        return U256::zero();
+    }
+    apply_basis_points(amount, flash_fee_rate)
+}
+
+/// Calls the `on_flash_loan` hook of the latest version of a borrower contract, after `amount`
+/// tokens were minted to it on behalf of the `initiator`.
+///
+/// A hook reverting reverts the whole execution, and a hook returning `false` rejects the loan.
+pub(crate) fn call_on_flash_loan(
+    borrower: ContractPackageHash,
+    initiator: Key,
+    amount: U256,
+    fee: U256,
+    data: Bytes,
+) -> Result<(), Cep18Error> {
+    let accepted: bool = runtime::call_versioned_contract(
+        borrower,
+        None,
+        ON_FLASH_LOAN_ENTRY_POINT_NAME,
+        runtime_args! {
+            INITIATOR => initiator,
+            AMOUNT => amount,
+            FEE => fee,
+            DATA => data,
+        },
+    );
+    if !accepted {
+        return Err(Cep18Error::FlashLoanRejected);
+    }
+    Ok(())
+}
@@ -0,0 +1,47 @@
+//! Implementation of account freezing.
+use core::convert::TryFrom;
+
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{Key, URef};
+
+use crate::{
+    constants::{ENABLE_FREEZE, FROZEN},
+    error::Cep18Error,
+    modalities::Freeze,
+    utils::{self, make_dictionary_item_key},
+};
+
+/// Getter for the "frozen" dictionary URef.
+pub(crate) fn get_frozen_uref() -> URef {
+    utils::get_uref(FROZEN)
+}
+
+/// Returns `true` if the `Freeze` modality was enabled at install.
+pub(crate) fn freeze_enabled() -> bool {
+    let enable_freeze = utils::read_optional_from::<u8>(ENABLE_FREEZE).unwrap_or_default();
+    Freeze::try_from(enable_freeze).unwrap_or_revert() == Freeze::Freezable
+}
+
+/// Writes the frozen state of a specified account into a dictionary.
+pub(crate) fn write_frozen_to(frozen_uref: URef, address: Key, frozen: bool) {
+    let dictionary_item_key = make_dictionary_item_key(address);
+    storage::dictionary_put(frozen_uref, &dictionary_item_key, frozen);
+}
+
+/// Reads the frozen state of a specified account.
+///
+/// Accounts that were never frozen are not present in the dictionary and read as not frozen.
+pub(crate) fn read_frozen_from(frozen_uref: URef, address: Key) -> bool {
+    let dictionary_item_key = make_dictionary_item_key(address);
+    storage::dictionary_get(frozen_uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Returns an error if the `Freeze` modality is enabled and the given account is frozen.
+pub(crate) fn check_not_frozen(address: Key) -> Result<(), Cep18Error> {
+    if freeze_enabled() && read_frozen_from(get_frozen_uref(), address) {
+        return Err(Cep18Error::AccountFrozen);
+    }
+    Ok(())
+}
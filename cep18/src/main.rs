@@ -4,13 +4,28 @@
 extern crate alloc;
 
 mod allowances;
+mod allowlist;
 mod balances;
+mod checkpoints;
 pub mod constants;
 pub mod entry_points;
 mod error;
 mod events;
+mod flash_mint;
+mod frozen;
+mod mint_rate_limit;
+mod minter_allowance;
 mod modalities;
+mod permit;
+mod receiver;
+mod roles;
+mod snapshots;
+mod transfer_fee;
 mod utils;
+mod vesting;
+mod votes;
+
+use core::convert::TryFrom;
 
 use alloc::{
     collections::BTreeMap,
@@ -20,37 +35,54 @@ use alloc::{
     vec::Vec,
 };
 
-use allowances::{get_allowances_uref, read_allowance_from, write_allowance_to};
-use balances::{get_balances_uref, read_balance_from, transfer_balance, write_balance_to};
+use allowances::{
+    get_allowances_uref, read_allowance_expiry_from, read_allowance_from,
+    write_allowance_expiry_to, write_allowance_to,
+};
+use balances::{get_balances_uref, write_balance_to};
 use entry_points::generate_entry_points;
 
 use casper_contract::{
     contract_api::{
         runtime::{self, get_caller, get_key, get_named_arg, put_key, revert},
-        storage::{self, dictionary_put},
+        storage,
     },
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{
-    bytesrepr::ToBytes, contracts::NamedKeys, runtime_args, CLValue, ContractHash,
-    ContractPackageHash, Key, RuntimeArgs, U256,
+    bytesrepr::Bytes, contracts::NamedKeys, runtime_args, CLValue, ContractHash,
+    ContractPackageHash, Key, PublicKey, RuntimeArgs, U256,
 };
 
 use constants::{
-    ACCESS_KEY_NAME_PREFIX, ADDRESS, ADMIN_LIST, ALLOWANCES, AMOUNT, BALANCES,
-    CONTRACT_NAME_PREFIX, CONTRACT_VERSION_PREFIX, DECIMALS, ENABLE_MINT_BURN, EVENTS_MODE,
-    HASH_KEY_NAME_PREFIX, INIT_ENTRY_POINT_NAME, MINTER_LIST, NAME, NONE_LIST, OWNER, PACKAGE_HASH,
-    RECIPIENT, SECURITY_BADGES, SPENDER, SYMBOL, TOTAL_SUPPLY,
+    ACCESS_KEY_NAME_PREFIX, ACCOUNT, ACCOUNTS, ADDRESS, ADMIN_COUNT, ADMIN_LIST, ALLOWANCE,
+    ALLOWANCES, ALLOWLIST, ALLOWLISTER_LIST, ALLOW_LIST, AMOUNT, BALANCES, BATCH_TRANSFER_EVENTS,
+    BLOCKTIME, BORROWER, BURNER_LIST, CLIFF, CONTRACT_NAME_PREFIX, CONTRACT_VERSION_PREFIX, DATA,
+    DEADLINE, DECIMALS, DELEGATEE, DISALLOW_LIST, DURATION, ENABLE_FLASH_MINT, ENABLE_FREEZE,
+    ENABLE_MINT_BURN, ENABLE_PAUSE, ENABLE_VESTING, EVENTS_MODE, EXPIRES_AT, FEE_EXEMPT,
+    FEE_EXEMPT_LIST, FEE_NON_EXEMPT_LIST, FEE_RATE, FLASH_FEE_RATE, FROZEN, HASH_KEY_NAME_PREFIX,
+    HOLDER, INITIAL_BALANCES, INIT_ENTRY_POINT_NAME, LIMIT, MAX_FEE_RATE, MAX_SUPPLY, MEMO,
+    MIGRATE_ENTRY_POINT_NAME, MINTER, MINTER_LIST, MINTS, MINT_AND_BURN_LIST, MINT_RATE_LIMIT,
+    MINT_RATE_WINDOW, NAME, NONCE, NONE_LIST, OFFSET, OWNER, PACKAGE_HASH, PAUSED, PAUSER_LIST,
+    RECIPIENT, ROLE, ROLES, SIGNATURE, SNAPSHOT_ID, SPENDER, START, SYMBOL, TOTAL, TOTAL_SUPPLY,
+    TRANSFERS, TRANSFER_FEE, TRANSFER_RESTRICTION, TREASURY,
 };
 pub use error::Cep18Error;
 use events::{
-    init_events, Burn, ChangeSecurity, DecreaseAllowance, Event, IncreaseAllowance, Mint,
-    SetAllowance, Transfer, TransferFrom,
+    init_events, AdminAccepted, AdminProposalCancelled, AdminProposed, BatchTransfer, Burn,
+    ChangeAllowlist, ChangeFeeExemption, ChangeSecurity, DecreaseAllowance, Event, FeeCharged,
+    FeeRateChanged, FlashMint, Frozen, IncreaseAllowance, LowerMaxSupply, Mint,
+    MintRateLimitChanged, MinterAllowanceChanged, Paused, RoleGranted, RoleRevoked, SetAllowance,
+    Snapshot, Transfer, TransferFrom, TransferWithMemo, TreasuryChanged, Unfrozen, Unpaused,
+    VestingScheduleCreated,
 };
+use modalities::{BatchTransferEvents, Freeze, Pause, TransferFee, TransferRestriction, Vesting};
 use utils::{
-    get_immediate_caller_address, get_total_supply_uref, read_from, read_total_supply_from,
-    sec_check, write_total_supply_to, SecurityBadge,
+    get_immediate_caller_address, get_total_supply_uref, read_max_supply, read_optional_from,
+    read_total_supply_from, require_mint_burn_enabled, require_not_paused, require_pause_enabled,
+    sec_check, SecurityBadge,
 };
+use vesting::VestingSchedule;
 
 #[no_mangle]
 pub extern "C" fn name() {
@@ -72,6 +104,11 @@ pub extern "C" fn total_supply() {
     runtime::ret(CLValue::from_t(utils::read_from::<U256>(TOTAL_SUPPLY)).unwrap_or_revert());
 }
 
+#[no_mangle]
+pub extern "C" fn max_supply() {
+    runtime::ret(CLValue::from_t(read_max_supply()).unwrap_or_revert());
+}
+
 #[no_mangle]
 pub extern "C" fn balance_of() {
     let address: Key = runtime::get_named_arg(ADDRESS);
@@ -89,25 +126,43 @@ pub extern "C" fn allowance() {
     runtime::ret(CLValue::from_t(val).unwrap_or_revert());
 }
 
+#[no_mangle]
+pub extern "C" fn allowance_with_expiry() {
+    let spender: Key = runtime::get_named_arg(SPENDER);
+    let owner: Key = runtime::get_named_arg(OWNER);
+    let allowances_uref = get_allowances_uref();
+    let val: U256 = read_allowance_from(allowances_uref, owner, spender);
+    let expires_at: Option<u64> = read_allowance_expiry_from(allowances_uref, owner, spender);
+    runtime::ret(CLValue::from_t((val, expires_at)).unwrap_or_revert());
+}
+
 #[no_mangle]
 pub extern "C" fn approve() {
+    require_not_paused();
     let owner = utils::get_immediate_caller_address().unwrap_or_revert();
     let spender: Key = runtime::get_named_arg(SPENDER);
     if spender == owner {
         revert(Cep18Error::CannotTargetSelfUser);
     }
     let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let expires_at: Option<u64> = utils::get_optional_named_arg_with_user_errors(
+        EXPIRES_AT,
+        Cep18Error::InvalidAllowanceExpiry,
+    );
     let allowances_uref = get_allowances_uref();
     write_allowance_to(allowances_uref, owner, spender, amount);
+    write_allowance_expiry_to(allowances_uref, owner, spender, expires_at);
     events::record_event_dictionary(Event::SetAllowance(SetAllowance {
         owner,
         spender,
         allowance: amount,
+        expires_at,
     }))
 }
 
 #[no_mangle]
 pub extern "C" fn decrease_allowance() {
+    require_not_paused();
     let owner = utils::get_immediate_caller_address().unwrap_or_revert();
     let spender: Key = runtime::get_named_arg(SPENDER);
     if spender == owner {
@@ -126,8 +181,11 @@ pub extern "C" fn decrease_allowance() {
     }))
 }
 
+/// Increases the allowance of a spender over the caller's tokens. The optional `expires_at`
+/// replaces the expiry of the whole allowance, which is otherwise kept.
 #[no_mangle]
 pub extern "C" fn increase_allowance() {
+    require_not_paused();
     let owner = utils::get_immediate_caller_address().unwrap_or_revert();
     let spender: Key = runtime::get_named_arg(SPENDER);
     if spender == owner {
@@ -135,45 +193,271 @@ pub extern "C" fn increase_allowance() {
     }
     let amount: U256 = runtime::get_named_arg(AMOUNT);
     let allowances_uref = get_allowances_uref();
+    let expires_at: Option<u64> = utils::get_optional_named_arg_with_user_errors(
+        EXPIRES_AT,
+        Cep18Error::InvalidAllowanceExpiry,
+    )
+    .or_else(|| read_allowance_expiry_from(allowances_uref, owner, spender));
     let current_allowance = read_allowance_from(allowances_uref, owner, spender);
     let new_allowance = current_allowance.saturating_add(amount);
     write_allowance_to(allowances_uref, owner, spender, new_allowance);
+    write_allowance_expiry_to(allowances_uref, owner, spender, expires_at);
     events::record_event_dictionary(Event::IncreaseAllowance(IncreaseAllowance {
         owner,
         spender,
         allowance: new_allowance,
         inc_by: amount,
+        expires_at,
+    }))
+}
+
+/// Sets a spender's allowance from an approval the owner signed off-chain, letting anyone submit
+/// it on the owner's behalf.
+#[no_mangle]
+pub extern "C" fn permit() {
+    require_not_paused();
+    let owner_public_key: PublicKey = runtime::get_named_arg(OWNER);
+    let owner = Key::Account(owner_public_key.to_account_hash());
+    let spender: Key = runtime::get_named_arg(SPENDER);
+    if spender == owner {
+        revert(Cep18Error::CannotTargetSelfUser);
+    }
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let deadline: u64 = runtime::get_named_arg(DEADLINE);
+    let nonce: u64 = runtime::get_named_arg(NONCE);
+    let signature: Bytes = runtime::get_named_arg(SIGNATURE);
+
+    if u64::from(runtime::get_blocktime()) > deadline {
+        revert(Cep18Error::PermitExpired);
+    }
+    if nonce != permit::read_nonce_from(owner) {
+        revert(Cep18Error::InvalidNonce);
+    }
+    let message = permit::make_permit_message(&owner_public_key, spender, amount, deadline, nonce);
+    permit::verify_signature(&owner_public_key, &message, &signature).unwrap_or_revert();
+    permit::write_nonce_to(
+        owner,
+        nonce
+            .checked_add(1)
+            .unwrap_or_revert_with(Cep18Error::Overflow),
+    );
+
+    let allowances_uref = get_allowances_uref();
+    write_allowance_to(allowances_uref, owner, spender, amount);
+    write_allowance_expiry_to(allowances_uref, owner, spender, None);
+    events::record_event_dictionary(Event::SetAllowance(SetAllowance {
+        owner,
+        spender,
+        allowance: amount,
+        expires_at: None,
     }))
 }
 
+#[no_mangle]
+pub extern "C" fn nonces() {
+    let owner: Key = runtime::get_named_arg(OWNER);
+    let nonce: u64 = permit::read_nonce_from(owner);
+    runtime::ret(CLValue::from_t(nonce).unwrap_or_revert());
+}
+
 #[no_mangle]
 pub extern "C" fn transfer() {
+    require_not_paused();
     let sender = utils::get_immediate_caller_address().unwrap_or_revert();
     let recipient: Key = runtime::get_named_arg(RECIPIENT);
-    if sender == recipient {
-        revert(Cep18Error::CannotTargetSelfUser);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    do_transfer(sender, recipient, amount);
+}
+
+/// Transfers tokens like `transfer`, and reverts with `UnsafeRecipient` unless the recipient is an
+/// account or a contract package registered through `register_receiver`. The `on_cep18_received`
+/// hook of a contract recipient is then called like in `transfer_and_call`, with empty `data`.
+#[no_mangle]
+pub extern "C" fn safe_transfer() {
+    require_not_paused();
+    let sender = utils::get_immediate_caller_address().unwrap_or_revert();
+    let recipient: Key = runtime::get_named_arg(RECIPIENT);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let receiver = receiver::check_safe_recipient(recipient).unwrap_or_revert();
+
+    let amount = do_transfer(sender, recipient, amount);
+    if let Some(receiver) = receiver {
+        receiver::call_on_received(receiver, sender, amount, Bytes::new()).unwrap_or_revert();
     }
+}
+
+/// Transfers tokens like `transfer`, recording a `TransferWithMemo` event carrying `memo`, for
+/// example to attribute a deposit to a user.
+#[no_mangle]
+pub extern "C" fn transfer_with_memo() {
+    require_not_paused();
+    let sender = utils::get_immediate_caller_address().unwrap_or_revert();
+    let recipient: Key = runtime::get_named_arg(RECIPIENT);
     let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let memo: u64 = runtime::get_named_arg(MEMO);
+
+    let amount = do_transfer(sender, recipient, amount);
+    events::record_event_dictionary(Event::TransferWithMemo(TransferWithMemo {
+        sender,
+        recipient,
+        amount,
+        memo,
+    }));
+}
 
-    transfer_balance(sender, recipient, amount).unwrap_or_revert();
+/// Transfers tokens to a recipient contract package and calls its `on_cep18_received` hook with
+/// the amount received and `data`. The whole transfer reverts if the hook reverts or rejects it.
+#[no_mangle]
+pub extern "C" fn transfer_and_call() {
+    require_not_paused();
+    let sender = utils::get_immediate_caller_address().unwrap_or_revert();
+    let recipient: Key = runtime::get_named_arg(RECIPIENT);
+    let receiver = receiver::receiver_package(recipient).unwrap_or_revert();
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let data: Bytes = runtime::get_named_arg(DATA);
+
+    let amount = do_transfer(sender, recipient, amount);
+    receiver::call_on_received(receiver, sender, amount, data).unwrap_or_revert();
+}
+
+/// Transfers `amount` tokens from the `sender` to the `recipient`, charging the transfer fee, and
+/// records the `Transfer` and `FeeCharged` events. Returns the amount received by the recipient.
+///
+/// This function does not check whether the contract is paused nor validate the sender.
+fn do_transfer(sender: Key, recipient: Key, amount: U256) -> U256 {
+    if sender == recipient {
+        revert(Cep18Error::CannotTargetSelfUser);
+    }
+    let (amount, fee) =
+        transfer_fee::transfer_balance_with_fee(sender, recipient, amount).unwrap_or_revert();
     events::record_event_dictionary(Event::Transfer(Transfer {
         sender,
         recipient,
         amount,
-    }))
+    }));
+    record_fee_charged(sender, fee);
+    amount
+}
+
+/// Transfers tokens from the caller to each `(recipient, amount)` pair in `transfers`. Any failing
+/// transfer reverts the whole batch.
+#[no_mangle]
+pub extern "C" fn batch_transfer() {
+    require_not_paused();
+    let sender = utils::get_immediate_caller_address().unwrap_or_revert();
+    let transfers: Vec<(Key, U256)> = runtime::get_named_arg(TRANSFERS);
+    if transfers.iter().any(|(recipient, _)| *recipient == sender) {
+        revert(Cep18Error::CannotTargetSelfUser);
+    }
+
+    let (transfers, fee) =
+        transfer_fee::transfer_balances_with_fee(sender, &transfers).unwrap_or_revert();
+
+    let batch_transfer_events = read_optional_from::<u8>(BATCH_TRANSFER_EVENTS).unwrap_or_default();
+    match BatchTransferEvents::try_from(batch_transfer_events).unwrap_or_revert() {
+        BatchTransferEvents::PerTransfer => {
+            for (recipient, amount) in transfers {
+                events::record_event_dictionary(Event::Transfer(Transfer {
+                    sender,
+                    recipient,
+                    amount,
+                }))
+            }
+        }
+        BatchTransferEvents::Batch => {
+            events::record_event_dictionary(Event::BatchTransfer(BatchTransfer {
+                sender,
+                transfers,
+            }))
+        }
+    }
+    record_fee_charged(sender, fee);
 }
 
 #[no_mangle]
 pub extern "C" fn transfer_from() {
+    require_not_paused();
+    let spender = utils::get_immediate_caller_address().unwrap_or_revert();
+    let recipient: Key = runtime::get_named_arg(RECIPIENT);
+    let owner: Key = runtime::get_named_arg(OWNER);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    do_transfer_from(spender, owner, recipient, amount);
+}
+
+/// Transfers tokens like `transfer_from`, and reverts with `UnsafeRecipient` unless the recipient
+/// is an account or a contract package registered through `register_receiver`. The
+/// `on_cep18_received` hook of a contract recipient is then called with the owner as the sender.
+#[no_mangle]
+pub extern "C" fn safe_transfer_from() {
+    require_not_paused();
     let spender = utils::get_immediate_caller_address().unwrap_or_revert();
     let recipient: Key = runtime::get_named_arg(RECIPIENT);
     let owner: Key = runtime::get_named_arg(OWNER);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let receiver = receiver::check_safe_recipient(recipient).unwrap_or_revert();
+
+    let amount = do_transfer_from(spender, owner, recipient, amount);
+    if let (Some(receiver), Some(amount)) = (receiver, amount) {
+        receiver::call_on_received(receiver, owner, amount, Bytes::new()).unwrap_or_revert();
+    }
+}
+
+/// Registers the calling contract package as able to receive tokens through `safe_transfer` and
+/// `safe_transfer_from`. Reverts with `InvalidReceiver` unless called by a contract.
+#[no_mangle]
+pub extern "C" fn register_receiver() {
+    let caller = utils::get_immediate_caller_address().unwrap_or_revert();
+    let receiver = receiver::receiver_package(caller).unwrap_or_revert();
+    receiver::write_registered_receiver(receiver, true);
+}
+
+/// Unregisters the calling contract package, which then can no longer receive tokens through
+/// `safe_transfer` and `safe_transfer_from`. Reverts with `InvalidReceiver` unless called by a
+/// contract.
+#[no_mangle]
+pub extern "C" fn unregister_receiver() {
+    let caller = utils::get_immediate_caller_address().unwrap_or_revert();
+    let receiver = receiver::receiver_package(caller).unwrap_or_revert();
+    receiver::write_registered_receiver(receiver, false);
+}
+
+/// Transfers tokens like `transfer_from`, recording a `TransferWithMemo` event carrying `memo`.
+#[no_mangle]
+pub extern "C" fn transfer_from_with_memo() {
+    require_not_paused();
+    let spender = utils::get_immediate_caller_address().unwrap_or_revert();
+    let recipient: Key = runtime::get_named_arg(RECIPIENT);
+    let owner: Key = runtime::get_named_arg(OWNER);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let memo: u64 = runtime::get_named_arg(MEMO);
+
+    if let Some(amount) = do_transfer_from(spender, owner, recipient, amount) {
+        events::record_event_dictionary(Event::TransferWithMemo(TransferWithMemo {
+            sender: owner,
+            recipient,
+            amount,
+            memo,
+        }));
+    }
+}
+
+/// Transfers `amount` tokens of the `owner` to the `recipient` out of the allowance of the
+/// `spender`, charging the transfer fee, and records the `TransferFrom` and `FeeCharged` events.
+///
+/// Returns the amount received by the recipient, or `None` when `amount` is zero and nothing was
+/// transferred, in which case the parties are still checked against the freeze and the
+/// allowlist. This function does not check whether the contract is paused nor validate the
+/// spender.
+fn do_transfer_from(spender: Key, owner: Key, recipient: Key, amount: U256) -> Option<U256> {
     if owner == recipient {
         revert(Cep18Error::CannotTargetSelfUser);
     }
-    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    frozen::check_not_frozen(spender).unwrap_or_revert();
     if amount.is_zero() {
-        return;
+        // Zero amounts are skipped by the balance transfer, once the owner and recipient are
+        // checked.
+        balances::transfer_balances(owner, &[(recipient, amount)]).unwrap_or_revert();
+        return None;
     }
 
     let allowances_uref = get_allowances_uref();
@@ -183,83 +467,540 @@ pub extern "C" fn transfer_from() {
         .ok_or(Cep18Error::InsufficientAllowance)
         .unwrap_or_revert();
 
-    transfer_balance(owner, recipient, amount).unwrap_or_revert();
+    let (amount, fee) =
+        transfer_fee::transfer_balance_with_fee(owner, recipient, amount).unwrap_or_revert();
     write_allowance_to(allowances_uref, owner, spender, new_spender_allowance);
     events::record_event_dictionary(Event::TransferFrom(TransferFrom {
         spender,
         owner,
         recipient,
         amount,
+    }));
+    record_fee_charged(owner, fee);
+    Some(amount)
+}
+
+/// Records a `FeeCharged` event when a transfer of the `payer` was charged a non-zero fee.
+fn record_fee_charged(payer: Key, fee: U256) {
+    if fee.is_zero() {
+        return;
+    }
+    events::record_event_dictionary(Event::FeeCharged(FeeCharged {
+        payer,
+        treasury: transfer_fee::read_treasury(),
+        fee,
     }))
 }
 
 #[no_mangle]
 pub extern "C" fn mint() {
-    if 0 == read_from::<u8>(ENABLE_MINT_BURN) {
-        revert(Cep18Error::MintBurnDisabled);
-    }
+    require_mint_burn_enabled();
+    require_not_paused();
 
-    sec_check(vec![SecurityBadge::Admin, SecurityBadge::Minter]);
+    sec_check(vec![
+        SecurityBadge::Admin,
+        SecurityBadge::Minter,
+        SecurityBadge::MintAndBurn,
+    ]);
 
     let owner: Key = runtime::get_named_arg(OWNER);
     let amount: U256 = runtime::get_named_arg(AMOUNT);
+    allowlist::check_allowlisted(owner, Cep18Error::RecipientNotAllowlisted).unwrap_or_revert();
+    frozen::check_not_frozen(owner).unwrap_or_revert();
 
-    let balances_uref = get_balances_uref();
-    let total_supply_uref = get_total_supply_uref();
-    let new_balance = {
-        let balance = read_balance_from(balances_uref, owner);
-        balance
-            .checked_add(amount)
-            .ok_or(Cep18Error::Overflow)
-            .unwrap_or_revert()
-    };
-    let new_total_supply = {
-        let total_supply: U256 = read_total_supply_from(total_supply_uref);
-        total_supply
-            .checked_add(amount)
-            .ok_or(Cep18Error::Overflow)
-            .unwrap_or_revert()
-    };
-    write_balance_to(balances_uref, owner, new_balance);
-    write_total_supply_to(total_supply_uref, new_total_supply);
+    let minter = get_immediate_caller_address().unwrap_or_revert();
+    minter_allowance::spend_minter_allowance(minter, amount).unwrap_or_revert();
+    mint_rate_limit::record_mint(minter, amount).unwrap_or_revert();
+    balances::mint_balances(&[(owner, amount)]).unwrap_or_revert();
     events::record_event_dictionary(Event::Mint(Mint {
         recipient: owner,
         amount,
     }))
 }
 
+/// Mints tokens to each `(owner, amount)` pair in `mints`, updating the total supply once.
 #[no_mangle]
-pub extern "C" fn burn() {
-    if 0 == read_from::<u8>(ENABLE_MINT_BURN) {
-        revert(Cep18Error::MintBurnDisabled);
+pub extern "C" fn batch_mint() {
+    require_mint_burn_enabled();
+    require_not_paused();
+
+    sec_check(vec![
+        SecurityBadge::Admin,
+        SecurityBadge::Minter,
+        SecurityBadge::MintAndBurn,
+    ]);
+
+    let mints: Vec<(Key, U256)> = runtime::get_named_arg(MINTS);
+    for (owner, _) in &mints {
+        allowlist::check_allowlisted(*owner, Cep18Error::RecipientNotAllowlisted)
+            .unwrap_or_revert();
+        frozen::check_not_frozen(*owner).unwrap_or_revert();
     }
 
+    let total_amount = mints
+        .iter()
+        .try_fold(U256::zero(), |total, (_, amount)| {
+            total.checked_add(*amount)
+        })
+        .ok_or(Cep18Error::Overflow)
+        .unwrap_or_revert();
+    let minter = get_immediate_caller_address().unwrap_or_revert();
+    minter_allowance::spend_minter_allowance(minter, total_amount).unwrap_or_revert();
+    mint_rate_limit::record_mint(minter, total_amount).unwrap_or_revert();
+    balances::mint_balances(&mints).unwrap_or_revert();
+    for (recipient, amount) in mints {
+        events::record_event_dictionary(Event::Mint(Mint { recipient, amount }))
+    }
+}
+
+/// Admin EntryPoint to set the remaining mint quota of a minter. `None` lets the minter mint
+/// without limit.
+#[no_mangle]
+pub extern "C" fn set_minter_allowance() {
+    sec_check(vec![SecurityBadge::Admin]);
+    let minter: Key = runtime::get_named_arg(MINTER);
+    let allowance: Option<U256> = runtime::get_named_arg(ALLOWANCE);
+    minter_allowance::write_minter_allowance_to(minter, allowance);
+    events::record_event_dictionary(Event::MinterAllowanceChanged(MinterAllowanceChanged {
+        admin: get_immediate_caller_address().unwrap_or_revert(),
+        minter,
+        allowance,
+    }))
+}
+
+/// Returns the remaining mint quota of a minter, `None` meaning unlimited.
+#[no_mangle]
+pub extern "C" fn minter_allowance() {
+    let minter: Key = runtime::get_named_arg(MINTER);
+    let allowance: Option<U256> = minter_allowance::read_minter_allowance_from(minter);
+    runtime::ret(CLValue::from_t(allowance).unwrap_or_revert());
+}
+
+/// Admin EntryPoint to limit the amount each minter can mint per window of `mint_rate_window`
+/// milliseconds to `mint_rate_limit`. A window of `0` lifts the limit.
+#[no_mangle]
+pub extern "C" fn set_mint_rate_limit() {
+    sec_check(vec![SecurityBadge::Admin]);
+    let limit: U256 = runtime::get_named_arg(MINT_RATE_LIMIT);
+    let window: u64 = runtime::get_named_arg(MINT_RATE_WINDOW);
+    mint_rate_limit::write_mint_rate_limit(limit, window);
+    events::record_event_dictionary(Event::MintRateLimitChanged(MintRateLimitChanged {
+        admin: get_immediate_caller_address().unwrap_or_revert(),
+        limit,
+        window,
+    }))
+}
+
+/// Burns tokens of the owner. Anyone can burn their own tokens, while Burners and MintAndBurn
+/// holders can burn from any account, in which case they are recorded as `spender`. Admins need
+/// one of these roles as well to burn from other accounts.
+#[no_mangle]
+pub extern "C" fn burn() {
+    require_mint_burn_enabled();
+    require_not_paused();
+
     let owner: Key = runtime::get_named_arg(OWNER);
+    let caller = get_immediate_caller_address().unwrap_or_revert();
+
+    let spender = if owner == caller {
+        None
+    } else {
+        if !roles::read_roles(caller)
+            .intersects(&[SecurityBadge::Burner, SecurityBadge::MintAndBurn])
+        {
+            revert(Cep18Error::InvalidBurnTarget);
+        }
+        Some(caller)
+    };
+    frozen::check_not_frozen(owner).unwrap_or_revert();
+
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    balances::burn_balance(owner, amount).unwrap_or_revert();
+    events::record_event_dictionary(Event::Burn(Burn {
+        owner,
+        amount,
+        spender,
+    }))
+}
+
+/// Burns tokens of the owner on behalf of the caller, consuming the caller's allowance like
+/// `transfer_from`.
+#[no_mangle]
+pub extern "C" fn burn_from() {
+    require_mint_burn_enabled();
+    require_not_paused();
+
+    let spender = get_immediate_caller_address().unwrap_or_revert();
+    let owner: Key = runtime::get_named_arg(OWNER);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    frozen::check_not_frozen(spender).unwrap_or_revert();
+    frozen::check_not_frozen(owner).unwrap_or_revert();
+    if amount.is_zero() {
+        return;
+    }
+
+    let allowances_uref = get_allowances_uref();
+    let spender_allowance: U256 = read_allowance_from(allowances_uref, owner, spender);
+    let new_spender_allowance = spender_allowance
+        .checked_sub(amount)
+        .ok_or(Cep18Error::InsufficientAllowance)
+        .unwrap_or_revert();
+
+    balances::burn_balance(owner, amount).unwrap_or_revert();
+    write_allowance_to(allowances_uref, owner, spender, new_spender_allowance);
+    events::record_event_dictionary(Event::Burn(Burn {
+        owner,
+        amount,
+        spender: Some(spender),
+    }))
+}
 
-    if owner != get_immediate_caller_address().unwrap_or_revert() {
-        revert(Cep18Error::InvalidBurnTarget);
+/// Mints `amount` tokens to a borrower contract package and calls its `on_flash_loan` hook, then
+/// burns the amount plus the flash fee from the borrower. The whole flash mint reverts if the hook
+/// reverts or rejects the loan, or if the borrower cannot repay. Requires flash mints to be enabled
+/// at install.
+#[no_mangle]
+pub extern "C" fn flash_mint() {
+    if !flash_mint::flash_mint_enabled() {
+        revert(Cep18Error::FlashMintDisabled);
     }
+    require_not_paused();
 
+    let initiator = get_immediate_caller_address().unwrap_or_revert();
+    let borrower: Key = runtime::get_named_arg(BORROWER);
+    let borrower_package = receiver::receiver_package(borrower).unwrap_or_revert();
     let amount: U256 = runtime::get_named_arg(AMOUNT);
-    let balances_uref = get_balances_uref();
-    let total_supply_uref = get_total_supply_uref();
-    let new_balance = {
-        let balance = read_balance_from(balances_uref, owner);
-        balance
-            .checked_sub(amount)
-            .ok_or(Cep18Error::InsufficientBalance)
-            .unwrap_or_revert()
-    };
-    let new_total_supply = {
-        let total_supply = read_total_supply_from(total_supply_uref);
-        total_supply
-            .checked_sub(amount)
-            .ok_or(Cep18Error::Overflow)
-            .unwrap_or_revert()
+    let data: Bytes = runtime::get_named_arg(DATA);
+    if amount > flash_mint::read_max_flash_loan() {
+        revert(Cep18Error::ExceedsMaxFlashLoan);
+    }
+    allowlist::check_allowlisted(borrower, Cep18Error::RecipientNotAllowlisted).unwrap_or_revert();
+    frozen::check_not_frozen(borrower).unwrap_or_revert();
+
+    let fee = flash_mint::compute_flash_fee(amount);
+    balances::mint_balances(&[(borrower, amount)]).unwrap_or_revert();
+    events::record_event_dictionary(Event::Mint(Mint {
+        recipient: borrower,
+        amount,
+    }));
+    flash_mint::call_on_flash_loan(borrower_package, initiator, amount, fee, data)
+        .unwrap_or_revert();
+
+    let repayment = amount
+        .checked_add(fee)
+        .ok_or(Cep18Error::Overflow)
+        .unwrap_or_revert();
+    balances::burn_balance(borrower, repayment)
+        .map_err(|_| Cep18Error::FlashLoanNotRepaid)
+        .unwrap_or_revert();
+    events::record_event_dictionary(Event::Burn(Burn {
+        owner: borrower,
+        amount: repayment,
+        spender: None,
+    }));
+    events::record_event_dictionary(Event::FlashMint(FlashMint {
+        initiator,
+        borrower,
+        amount,
+        fee,
+    }))
+}
+
+/// Returns the maximum amount that can be flash minted, `0` when flash mints are disabled.
+#[no_mangle]
+pub extern "C" fn max_flash_loan() {
+    runtime::ret(CLValue::from_t(flash_mint::read_max_flash_loan()).unwrap_or_revert());
+}
+
+/// Returns the fee charged on a flash mint of `amount`.
+#[no_mangle]
+pub extern "C" fn flash_fee() {
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    runtime::ret(CLValue::from_t(flash_mint::compute_flash_fee(amount)).unwrap_or_revert());
+}
+
+/// Pauses the contract, making token movements and approvals revert until `unpause` is called.
+/// Requires the `Pause` modality to be enabled at install.
+#[no_mangle]
+pub extern "C" fn pause() {
+    require_pause_enabled();
+    sec_check(vec![SecurityBadge::Admin, SecurityBadge::Pauser]);
+    storage::write(utils::get_uref(PAUSED), true);
+    events::record_event_dictionary(Event::Paused(Paused {
+        pauser: get_immediate_caller_address().unwrap_or_revert(),
+    }))
+}
+
+/// Lifts a pause previously set by `pause`.
+#[no_mangle]
+pub extern "C" fn unpause() {
+    require_pause_enabled();
+    sec_check(vec![SecurityBadge::Admin, SecurityBadge::Pauser]);
+    storage::write(utils::get_uref(PAUSED), false);
+    events::record_event_dictionary(Event::Unpaused(Unpaused {
+        pauser: get_immediate_caller_address().unwrap_or_revert(),
+    }))
+}
+
+/// Admin EntryPoint to freeze an account, preventing it from sending, receiving or burning
+/// tokens. Requires the `Freeze` modality to be enabled at install.
+#[no_mangle]
+pub extern "C" fn freeze_account() {
+    if !frozen::freeze_enabled() {
+        revert(Cep18Error::FreezeDisabled);
+    }
+    sec_check(vec![SecurityBadge::Admin]);
+    let account: Key = runtime::get_named_arg(ACCOUNT);
+    frozen::write_frozen_to(frozen::get_frozen_uref(), account, true);
+    events::record_event_dictionary(Event::Frozen(Frozen {
+        admin: get_immediate_caller_address().unwrap_or_revert(),
+        account,
+    }))
+}
+
+/// Admin EntryPoint to lift a freeze set by `freeze_account`.
+#[no_mangle]
+pub extern "C" fn unfreeze_account() {
+    if !frozen::freeze_enabled() {
+        revert(Cep18Error::FreezeDisabled);
+    }
+    sec_check(vec![SecurityBadge::Admin]);
+    let account: Key = runtime::get_named_arg(ACCOUNT);
+    frozen::write_frozen_to(frozen::get_frozen_uref(), account, false);
+    events::record_event_dictionary(Event::Unfrozen(Unfrozen {
+        admin: get_immediate_caller_address().unwrap_or_revert(),
+        account,
+    }))
+}
+
+/// EntryPoint for Admins and Allowlisters to add accounts to or remove accounts from the
+/// allowlist. Requires the `Allowlist` transfer restriction to be selected at install.
+/// Change strength: disallow > allow
+#[no_mangle]
+pub extern "C" fn change_allowlist() {
+    if !allowlist::allowlist_enabled() {
+        revert(Cep18Error::AllowlistDisabled);
+    }
+    sec_check(vec![SecurityBadge::Admin, SecurityBadge::Allowlister]);
+    let allow_list: Option<Vec<Key>> =
+        utils::get_optional_named_arg_with_user_errors(ALLOW_LIST, Cep18Error::InvalidAllowList);
+    let disallow_list: Option<Vec<Key>> = utils::get_optional_named_arg_with_user_errors(
+        DISALLOW_LIST,
+        Cep18Error::InvalidDisallowList,
+    );
+
+    let mut allowlist_change_map: BTreeMap<Key, bool> = BTreeMap::new();
+    if let Some(allow_list) = allow_list {
+        for account_key in allow_list {
+            allowlist_change_map.insert(account_key, true);
+        }
+    }
+    if let Some(disallow_list) = disallow_list {
+        for account_key in disallow_list {
+            allowlist_change_map.insert(account_key, false);
+        }
+    }
+
+    allowlist::change_allowlist(&allowlist_change_map);
+    events::record_event_dictionary(Event::ChangeAllowlist(ChangeAllowlist {
+        manager: get_immediate_caller_address().unwrap_or_revert(),
+        allowlist_change_map,
+    }));
+}
+
+/// Admin EntryPoint to take a snapshot of the balances and the total supply, returning its id.
+#[no_mangle]
+pub extern "C" fn snapshot() {
+    sec_check(vec![SecurityBadge::Admin]);
+    let snapshot_id = snapshots::snapshot();
+    events::record_event_dictionary(Event::Snapshot(Snapshot {
+        admin: get_immediate_caller_address().unwrap_or_revert(),
+        snapshot_id,
+    }));
+    runtime::ret(CLValue::from_t(snapshot_id).unwrap_or_revert());
+}
+
+/// Returns the balance of an address at a given snapshot.
+#[no_mangle]
+pub extern "C" fn balance_of_at() {
+    let address: Key = runtime::get_named_arg(ADDRESS);
+    let snapshot_id: u64 = runtime::get_named_arg(SNAPSHOT_ID);
+    let balance = snapshots::read_balance_at(address, snapshot_id).unwrap_or_revert();
+    runtime::ret(CLValue::from_t(balance).unwrap_or_revert());
+}
+
+/// Returns the total supply at a given snapshot.
+#[no_mangle]
+pub extern "C" fn total_supply_at() {
+    let snapshot_id: u64 = runtime::get_named_arg(SNAPSHOT_ID);
+    let total_supply = snapshots::read_total_supply_at(snapshot_id).unwrap_or_revert();
+    runtime::ret(CLValue::from_t(total_supply).unwrap_or_revert());
+}
+
+/// Delegates the votes of the caller to `delegatee`, which can be the caller itself.
+#[no_mangle]
+pub extern "C" fn delegate() {
+    let delegator = get_immediate_caller_address().unwrap_or_revert();
+    let delegatee: Key = runtime::get_named_arg(DELEGATEE);
+    votes::delegate(delegator, delegatee).unwrap_or_revert();
+}
+
+/// Returns the delegate of an account, `None` if it never delegated.
+#[no_mangle]
+pub extern "C" fn delegates() {
+    let account: Key = runtime::get_named_arg(ACCOUNT);
+    runtime::ret(CLValue::from_t(votes::read_delegate_from(account)).unwrap_or_revert());
+}
+
+/// Returns the current votes of an account.
+#[no_mangle]
+pub extern "C" fn get_votes() {
+    let account: Key = runtime::get_named_arg(ACCOUNT);
+    runtime::ret(CLValue::from_t(votes::read_votes_from(account)).unwrap_or_revert());
+}
+
+/// Returns the votes of an account at a past block time.
+#[no_mangle]
+pub extern "C" fn get_past_votes() {
+    let account: Key = runtime::get_named_arg(ACCOUNT);
+    let blocktime: u64 = runtime::get_named_arg(BLOCKTIME);
+    let votes = votes::read_past_votes_from(account, blocktime).unwrap_or_revert();
+    runtime::ret(CLValue::from_t(votes).unwrap_or_revert());
+}
+
+/// Admin EntryPoint to grant `total` tokens to a holder under a vesting schedule. The tokens are
+/// transferred from the calling Admin and released linearly over `duration` milliseconds from
+/// `start`, none before the `cliff`. Requires the `Vesting` modality to be enabled at install.
+#[no_mangle]
+pub extern "C" fn create_vesting_schedule() {
+    if !vesting::vesting_enabled() {
+        revert(Cep18Error::VestingDisabled);
+    }
+    require_not_paused();
+    sec_check(vec![SecurityBadge::Admin]);
+    let admin = get_immediate_caller_address().unwrap_or_revert();
+    let holder: Key = runtime::get_named_arg(HOLDER);
+    if admin == holder {
+        revert(Cep18Error::CannotTargetSelfUser);
+    }
+    let schedule = VestingSchedule {
+        start: runtime::get_named_arg(START),
+        cliff: runtime::get_named_arg(CLIFF),
+        duration: runtime::get_named_arg(DURATION),
+        total: runtime::get_named_arg(TOTAL),
     };
-    write_balance_to(balances_uref, owner, new_balance);
-    write_total_supply_to(total_supply_uref, new_total_supply);
-    events::record_event_dictionary(Event::Burn(Burn { owner, amount }))
+    vesting::create_vesting_schedule(holder, &schedule).unwrap_or_revert();
+    balances::transfer_balances(admin, &[(holder, schedule.total)]).unwrap_or_revert();
+    events::record_event_dictionary(Event::Transfer(Transfer {
+        sender: admin,
+        recipient: holder,
+        amount: schedule.total,
+    }));
+    events::record_event_dictionary(Event::VestingScheduleCreated(VestingScheduleCreated {
+        admin,
+        holder,
+        start: schedule.start,
+        cliff: schedule.cliff,
+        duration: schedule.duration,
+        total: schedule.total,
+    }));
+}
+
+/// Returns the amount of tokens of an address still locked by its vesting schedule.
+#[no_mangle]
+pub extern "C" fn locked_balance_of() {
+    let address: Key = runtime::get_named_arg(ADDRESS);
+    runtime::ret(CLValue::from_t(vesting::read_locked_balance(address)).unwrap_or_revert());
+}
+
+/// Returns the amount of tokens of an address vested so far by its vesting schedule.
+#[no_mangle]
+pub extern "C" fn vested_amount() {
+    let address: Key = runtime::get_named_arg(ADDRESS);
+    runtime::ret(CLValue::from_t(vesting::read_vested_amount(address)).unwrap_or_revert());
+}
+
+/// Admin EntryPoint to lower the maximum supply. The maximum supply can never be raised, nor set
+/// below the current total supply.
+#[no_mangle]
+pub extern "C" fn lower_max_supply() {
+    sec_check(vec![SecurityBadge::Admin]);
+    let max_supply: U256 = runtime::get_named_arg(MAX_SUPPLY);
+    if max_supply > read_max_supply()
+        || max_supply < read_total_supply_from(get_total_supply_uref())
+    {
+        revert(Cep18Error::InvalidMaxSupply);
+    }
+    utils::write_named_value(MAX_SUPPLY, max_supply);
+    events::record_event_dictionary(Event::LowerMaxSupply(LowerMaxSupply {
+        admin: get_immediate_caller_address().unwrap_or_revert(),
+        max_supply,
+    }))
+}
+
+/// Admin EntryPoint to set the transfer fee rate in basis points. The rate cannot exceed the
+/// `max_fee_rate` set at install. Requires the transfer fee to be enabled at install.
+#[no_mangle]
+pub extern "C" fn set_fee_rate() {
+    if !transfer_fee::transfer_fee_enabled() {
+        revert(Cep18Error::TransferFeeDisabled);
+    }
+    sec_check(vec![SecurityBadge::Admin]);
+    let fee_rate: u32 = runtime::get_named_arg(FEE_RATE);
+    transfer_fee::write_fee_rate(fee_rate).unwrap_or_revert();
+    events::record_event_dictionary(Event::FeeRateChanged(FeeRateChanged {
+        admin: get_immediate_caller_address().unwrap_or_revert(),
+        fee_rate,
+    }))
+}
+
+/// Admin EntryPoint to set the account receiving the transfer fees. Requires the transfer fee to
+/// be enabled at install.
+#[no_mangle]
+pub extern "C" fn set_treasury() {
+    if !transfer_fee::transfer_fee_enabled() {
+        revert(Cep18Error::TransferFeeDisabled);
+    }
+    sec_check(vec![SecurityBadge::Admin]);
+    let treasury: Key = runtime::get_named_arg(TREASURY);
+    transfer_fee::write_treasury(treasury);
+    events::record_event_dictionary(Event::TreasuryChanged(TreasuryChanged {
+        admin: get_immediate_caller_address().unwrap_or_revert(),
+        treasury,
+    }))
+}
+
+/// Admin EntryPoint to add accounts to or remove them from the transfer fee exemption list.
+/// Requires the transfer fee to be enabled at install.
+/// Change strength: non-exempt > exempt
+#[no_mangle]
+pub extern "C" fn change_fee_exemption() {
+    if !transfer_fee::transfer_fee_enabled() {
+        revert(Cep18Error::TransferFeeDisabled);
+    }
+    sec_check(vec![SecurityBadge::Admin]);
+    let fee_exempt_list: Option<Vec<Key>> = utils::get_optional_named_arg_with_user_errors(
+        FEE_EXEMPT_LIST,
+        Cep18Error::InvalidFeeExemptList,
+    );
+    let fee_non_exempt_list: Option<Vec<Key>> = utils::get_optional_named_arg_with_user_errors(
+        FEE_NON_EXEMPT_LIST,
+        Cep18Error::InvalidFeeNonExemptList,
+    );
+
+    let mut fee_exemption_change_map: BTreeMap<Key, bool> = BTreeMap::new();
+    for account_key in fee_exempt_list.unwrap_or_default() {
+        fee_exemption_change_map.insert(account_key, true);
+    }
+    for account_key in fee_non_exempt_list.unwrap_or_default() {
+        fee_exemption_change_map.insert(account_key, false);
+    }
+
+    transfer_fee::change_fee_exemption(&fee_exemption_change_map);
+    events::record_event_dictionary(Event::ChangeFeeExemption(ChangeFeeExemption {
+        admin: get_immediate_caller_address().unwrap_or_revert(),
+        fee_exemption_change_map,
+    }));
 }
 
 /// Initiates the contracts states. Only used by the installer call,
@@ -272,65 +1013,117 @@ pub extern "C" fn init() {
     let package_hash = get_named_arg::<Key>(PACKAGE_HASH);
     put_key(PACKAGE_HASH, package_hash);
     storage::new_dictionary(ALLOWANCES).unwrap_or_revert();
+    if frozen::freeze_enabled() {
+        storage::new_dictionary(FROZEN).unwrap_or_revert();
+    }
+    if allowlist::allowlist_enabled() {
+        storage::new_dictionary(ALLOWLIST).unwrap_or_revert();
+    }
+    if transfer_fee::transfer_fee_enabled() {
+        let fee_exempt_uref = storage::new_dictionary(FEE_EXEMPT).unwrap_or_revert();
+        let fee_exempt_list: Option<Vec<Key>> = utils::get_optional_named_arg_with_user_errors(
+            FEE_EXEMPT_LIST,
+            Cep18Error::InvalidFeeExemptList,
+        );
+        for account in fee_exempt_list.unwrap_or_default() {
+            storage::dictionary_put(
+                fee_exempt_uref,
+                &utils::make_dictionary_item_key(account),
+                true,
+            );
+        }
+    }
     let balances_uref = storage::new_dictionary(BALANCES).unwrap_or_revert();
     let initial_supply = runtime::get_named_arg(TOTAL_SUPPLY);
     let caller = get_caller();
     write_balance_to(balances_uref, caller.into(), initial_supply);
 
-    let security_badges_dict = storage::new_dictionary(SECURITY_BADGES).unwrap_or_revert();
-    dictionary_put(
-        security_badges_dict,
-        &base64::encode(Key::from(get_caller()).to_bytes().unwrap_or_revert()),
-        SecurityBadge::Admin,
-    );
+    storage::new_dictionary(ROLES).unwrap_or_revert();
+    roles::grant_role(caller.into(), SecurityBadge::Admin);
 
     let admin_list: Option<Vec<Key>> =
         utils::get_optional_named_arg_with_user_errors(ADMIN_LIST, Cep18Error::InvalidAdminList);
     let minter_list: Option<Vec<Key>> =
         utils::get_optional_named_arg_with_user_errors(MINTER_LIST, Cep18Error::InvalidMinterList);
+    let burner_list: Option<Vec<Key>> =
+        utils::get_optional_named_arg_with_user_errors(BURNER_LIST, Cep18Error::InvalidBurnerList);
+    let mint_and_burn_list: Option<Vec<Key>> = utils::get_optional_named_arg_with_user_errors(
+        MINT_AND_BURN_LIST,
+        Cep18Error::InvalidMintAndBurnList,
+    );
+    let pauser_list: Option<Vec<Key>> =
+        utils::get_optional_named_arg_with_user_errors(PAUSER_LIST, Cep18Error::InvalidPauserList);
+    let allowlister_list: Option<Vec<Key>> = utils::get_optional_named_arg_with_user_errors(
+        ALLOWLISTER_LIST,
+        Cep18Error::InvalidAllowlisterList,
+    );
 
     init_events();
 
-    if let Some(minter_list) = minter_list {
-        for minter in minter_list {
-            dictionary_put(
-                security_badges_dict,
-                &base64::encode(minter.to_bytes().unwrap_or_revert()),
-                SecurityBadge::Minter,
-            );
-        }
-    }
-    if let Some(admin_list) = admin_list {
-        for admin in admin_list {
-            dictionary_put(
-                security_badges_dict,
-                &base64::encode(admin.to_bytes().unwrap_or_revert()),
-                SecurityBadge::Admin,
-            );
+    // Roles accumulate, so an account listed in several lists holds all of their roles.
+    for (list, badge) in [
+        (minter_list, SecurityBadge::Minter),
+        (burner_list, SecurityBadge::Burner),
+        (mint_and_burn_list, SecurityBadge::MintAndBurn),
+        (pauser_list, SecurityBadge::Pauser),
+        (allowlister_list, SecurityBadge::Allowlister),
+        (admin_list, SecurityBadge::Admin),
+    ] {
+        for account in list.unwrap_or_default() {
+            roles::grant_role(account, badge);
         }
     }
     events::record_event_dictionary(Event::Mint(Mint {
         recipient: caller.into(),
         amount: initial_supply,
-    }))
+    }));
+
+    let initial_balances: Option<Vec<(Key, U256)>> = utils::get_optional_named_arg_with_user_errors(
+        INITIAL_BALANCES,
+        Cep18Error::InvalidInitialBalances,
+    );
+    if let Some(initial_balances) = initial_balances {
+        balances::mint_balances(&initial_balances).unwrap_or_revert();
+        for (recipient, amount) in initial_balances {
+            events::record_event_dictionary(Event::Mint(Mint { recipient, amount }))
+        }
+    }
+}
+
+/// Brings the state left by previous versions up to date after an upgrade, which calls it on the
+/// new version. The event schemas are stored again, as events may have been added or gained fields
+/// since the contract was installed. Calling it again has no effect.
+#[no_mangle]
+pub extern "C" fn migrate() {
+    events::upgrade_events_schema();
 }
 
 /// Admin EntryPoint to manipulate the security access granted to users.
-/// One user can only possess one access group badge.
-/// Change strength: None > Admin > Minter
+/// Each listed user is left with the single role of its list, replacing any roles they held; use
+/// `grant_role` and `revoke_role` to give a user several roles.
+/// Change strength: None > Admin > Allowlister > Pauser > MintAndBurn > Burner > Minter
 /// Change strength meaning by example: If user is added to both Minter and Admin they will be an
 /// Admin, also if a user is added to Admin and None then they will be removed from having rights.
-/// Beware: do not remove the last Admin because that will lock out all admin functionality.
+/// Changes that would leave no Admin revert with `LastAdmin`, see `renounce_admin`.
 #[no_mangle]
 pub extern "C" fn change_security() {
-    if 0 == read_from::<u8>(ENABLE_MINT_BURN) {
-        revert(Cep18Error::MintBurnDisabled);
-    }
     sec_check(vec![SecurityBadge::Admin]);
     let admin_list: Option<Vec<Key>> =
         utils::get_optional_named_arg_with_user_errors(ADMIN_LIST, Cep18Error::InvalidAdminList);
     let minter_list: Option<Vec<Key>> =
         utils::get_optional_named_arg_with_user_errors(MINTER_LIST, Cep18Error::InvalidMinterList);
+    let burner_list: Option<Vec<Key>> =
+        utils::get_optional_named_arg_with_user_errors(BURNER_LIST, Cep18Error::InvalidBurnerList);
+    let mint_and_burn_list: Option<Vec<Key>> = utils::get_optional_named_arg_with_user_errors(
+        MINT_AND_BURN_LIST,
+        Cep18Error::InvalidMintAndBurnList,
+    );
+    let pauser_list: Option<Vec<Key>> =
+        utils::get_optional_named_arg_with_user_errors(PAUSER_LIST, Cep18Error::InvalidPauserList);
+    let allowlister_list: Option<Vec<Key>> = utils::get_optional_named_arg_with_user_errors(
+        ALLOWLISTER_LIST,
+        Cep18Error::InvalidAllowlisterList,
+    );
     let none_list: Option<Vec<Key>> =
         utils::get_optional_named_arg_with_user_errors(NONE_LIST, Cep18Error::InvalidNoneList);
 
@@ -340,6 +1133,26 @@ pub extern "C" fn change_security() {
             badge_map.insert(account_key, SecurityBadge::Minter);
         }
     }
+    if let Some(burner_list) = burner_list {
+        for account_key in burner_list {
+            badge_map.insert(account_key, SecurityBadge::Burner);
+        }
+    }
+    if let Some(mint_and_burn_list) = mint_and_burn_list {
+        for account_key in mint_and_burn_list {
+            badge_map.insert(account_key, SecurityBadge::MintAndBurn);
+        }
+    }
+    if let Some(pauser_list) = pauser_list {
+        for account_key in pauser_list {
+            badge_map.insert(account_key, SecurityBadge::Pauser);
+        }
+    }
+    if let Some(allowlister_list) = allowlister_list {
+        for account_key in allowlister_list {
+            badge_map.insert(account_key, SecurityBadge::Allowlister);
+        }
+    }
     if let Some(admin_list) = admin_list {
         for account_key in admin_list {
             badge_map.insert(account_key, SecurityBadge::Admin);
@@ -355,11 +1168,170 @@ pub extern "C" fn change_security() {
     badge_map.remove(&caller);
 
     utils::change_sec_badge(&badge_map);
+    roles::check_admin_left(caller).unwrap_or_revert();
     events::record_event_dictionary(Event::ChangeSecurity(ChangeSecurity {
         admin: get_immediate_caller_address().unwrap_or_revert(),
         sec_change_map: badge_map,
     }));
 }
+
+/// Admin EntryPoint to add a role to an account, keeping the roles it already holds.
+#[no_mangle]
+pub extern "C" fn grant_role() {
+    sec_check(vec![SecurityBadge::Admin]);
+    let account: Key = runtime::get_named_arg(ACCOUNT);
+    let role = read_role_arg();
+    roles::grant_role(account, role);
+    events::record_event_dictionary(Event::RoleGranted(RoleGranted {
+        admin: get_immediate_caller_address().unwrap_or_revert(),
+        account,
+        role,
+    }))
+}
+
+/// Admin EntryPoint to remove a role from an account, keeping its other roles.
+/// Changes that would leave no Admin revert with `LastAdmin`, see `renounce_admin`.
+#[no_mangle]
+pub extern "C" fn revoke_role() {
+    sec_check(vec![SecurityBadge::Admin]);
+    let admin = get_immediate_caller_address().unwrap_or_revert();
+    let account: Key = runtime::get_named_arg(ACCOUNT);
+    let role = read_role_arg();
+    roles::revoke_role(account, role);
+    roles::check_admin_left(admin).unwrap_or_revert();
+    events::record_event_dictionary(Event::RoleRevoked(RoleRevoked {
+        admin,
+        account,
+        role,
+    }))
+}
+
+/// Admin EntryPoint to give up the caller's own Admin role. Unlike `revoke_role` and
+/// `change_security` this may remove the last Admin, which permanently locks out all admin
+/// functionality.
+#[no_mangle]
+pub extern "C" fn renounce_admin() {
+    sec_check(vec![SecurityBadge::Admin]);
+    let caller = get_immediate_caller_address().unwrap_or_revert();
+    roles::revoke_role(caller, SecurityBadge::Admin);
+    events::record_event_dictionary(Event::RoleRevoked(RoleRevoked {
+        admin: caller,
+        account: caller,
+        role: SecurityBadge::Admin,
+    }))
+}
+
+/// Admin EntryPoint to nominate an account as Admin. The nominee only becomes an Admin once they
+/// call `accept_admin`, and the proposer can withdraw the nomination with `cancel_admin_proposal`.
+#[no_mangle]
+pub extern "C" fn propose_admin() {
+    sec_check(vec![SecurityBadge::Admin]);
+    let proposer = get_immediate_caller_address().unwrap_or_revert();
+    let nominee: Key = runtime::get_named_arg(ACCOUNT);
+    if nominee == proposer {
+        revert(Cep18Error::CannotTargetSelfUser);
+    }
+    roles::write_admin_nomination(nominee, Some(proposer));
+    events::record_event_dictionary(Event::AdminProposed(AdminProposed { proposer, nominee }))
+}
+
+/// Makes the caller an Admin if they hold a nomination from an account that is still an Admin.
+#[no_mangle]
+pub extern "C" fn accept_admin() {
+    let nominee = get_immediate_caller_address().unwrap_or_revert();
+    let proposer =
+        roles::read_admin_nomination(nominee).unwrap_or_revert_with(Cep18Error::NoAdminNomination);
+    if !roles::read_roles(proposer).contains(SecurityBadge::Admin) {
+        revert(Cep18Error::InsufficientRights);
+    }
+    roles::write_admin_nomination(nominee, None);
+    roles::grant_role(nominee, SecurityBadge::Admin);
+    events::record_event_dictionary(Event::AdminAccepted(AdminAccepted { proposer, nominee }))
+}
+
+/// Withdraws a pending Admin nomination. Only the Admin who made it can cancel it.
+#[no_mangle]
+pub extern "C" fn cancel_admin_proposal() {
+    let caller = get_immediate_caller_address().unwrap_or_revert();
+    let nominee: Key = runtime::get_named_arg(ACCOUNT);
+    let proposer =
+        roles::read_admin_nomination(nominee).unwrap_or_revert_with(Cep18Error::NoAdminNomination);
+    if proposer != caller {
+        revert(Cep18Error::InsufficientRights);
+    }
+    roles::write_admin_nomination(nominee, None);
+    events::record_event_dictionary(Event::AdminProposalCancelled(AdminProposalCancelled {
+        proposer,
+        nominee,
+    }))
+}
+
+/// Returns whether an account holds a role.
+#[no_mangle]
+pub extern "C" fn has_role() {
+    let account: Key = runtime::get_named_arg(ACCOUNT);
+    let role = read_role_arg();
+    let has_role: bool = roles::read_roles(account).contains(role);
+    runtime::ret(CLValue::from_t(has_role).unwrap_or_revert());
+}
+
+/// Returns up to `limit` members of a role, starting at `offset`.
+#[no_mangle]
+pub extern "C" fn get_role_members() {
+    let role = read_role_arg();
+    ret_role_members(role);
+}
+
+/// Returns up to `limit` Admins, starting at `offset`.
+#[no_mangle]
+pub extern "C" fn get_admins() {
+    ret_role_members(SecurityBadge::Admin);
+}
+
+/// Returns up to `limit` Minters, starting at `offset`.
+#[no_mangle]
+pub extern "C" fn get_minters() {
+    ret_role_members(SecurityBadge::Minter);
+}
+
+/// Returns the strongest role of an account, or of the caller if no `account` is given.
+#[no_mangle]
+pub extern "C" fn get_badge() {
+    let account: Key =
+        utils::get_optional_named_arg_with_user_errors(ACCOUNT, Cep18Error::InvalidAccount)
+            .unwrap_or_else(|| get_immediate_caller_address().unwrap_or_revert());
+    let badge: SecurityBadge = roles::read_roles(account).badge();
+    runtime::ret(CLValue::from_t(badge).unwrap_or_revert());
+}
+
+/// Admin EntryPoint to list the given accounts as members of the roles they hold through a legacy
+/// badge. Contracts upgraded from a version with single badges only list a badge holder once its
+/// roles change, which this brings forward.
+#[no_mangle]
+pub extern "C" fn index_roles() {
+    sec_check(vec![SecurityBadge::Admin]);
+    let accounts: Vec<Key> = runtime::get_named_arg(ACCOUNTS);
+    for account in accounts {
+        roles::index_legacy_roles(account);
+    }
+}
+
+/// Returns the page of role members selected by the `offset` and `limit` runtime arguments.
+fn ret_role_members(role: SecurityBadge) {
+    let offset: u32 = runtime::get_named_arg(OFFSET);
+    let limit: u32 = runtime::get_named_arg(LIMIT);
+    let members: Vec<Key> = roles::read_role_members(role, offset, limit);
+    runtime::ret(CLValue::from_t(members).unwrap_or_revert());
+}
+
+/// Reads the `role` runtime argument, reverting with `InvalidRole` unless it names a role.
+fn read_role_arg() -> SecurityBadge {
+    let role: u8 = runtime::get_named_arg(ROLE);
+    match SecurityBadge::try_from(role).unwrap_or_revert() {
+        SecurityBadge::None => revert(Cep18Error::InvalidRole),
+        role => role,
+    }
+}
 pub fn upgrade(name: &str) {
     let entry_points = generate_entry_points();
 
@@ -388,12 +1360,20 @@ pub fn upgrade(name: &str) {
         &format!("{CONTRACT_VERSION_PREFIX}{name}"),
         storage::new_uref(contract_version).into(),
     );
+    // Call the new version to migrate the state left by the previous ones
+    runtime::call_contract::<()>(contract_hash, MIGRATE_ENTRY_POINT_NAME, runtime_args! {});
 }
 
 pub fn install_contract(name: &str) {
     let symbol: String = runtime::get_named_arg(SYMBOL);
     let decimals: u8 = runtime::get_named_arg(DECIMALS);
     let total_supply: U256 = runtime::get_named_arg(TOTAL_SUPPLY);
+    let max_supply: U256 =
+        utils::get_optional_named_arg_with_user_errors(MAX_SUPPLY, Cep18Error::InvalidMaxSupply)
+            .unwrap_or(U256::MAX);
+    if total_supply > max_supply {
+        revert(Cep18Error::ExceedsMaxSupply);
+    }
     let events_mode: u8 =
         utils::get_optional_named_arg_with_user_errors(EVENTS_MODE, Cep18Error::InvalidEventsMode)
             .unwrap_or(0u8);
@@ -402,12 +1382,107 @@ pub fn install_contract(name: &str) {
         utils::get_optional_named_arg_with_user_errors(ADMIN_LIST, Cep18Error::InvalidAdminList);
     let minter_list: Option<Vec<Key>> =
         utils::get_optional_named_arg_with_user_errors(MINTER_LIST, Cep18Error::InvalidMinterList);
+    let burner_list: Option<Vec<Key>> =
+        utils::get_optional_named_arg_with_user_errors(BURNER_LIST, Cep18Error::InvalidBurnerList);
+    let mint_and_burn_list: Option<Vec<Key>> = utils::get_optional_named_arg_with_user_errors(
+        MINT_AND_BURN_LIST,
+        Cep18Error::InvalidMintAndBurnList,
+    );
+    let pauser_list: Option<Vec<Key>> =
+        utils::get_optional_named_arg_with_user_errors(PAUSER_LIST, Cep18Error::InvalidPauserList);
+    let allowlister_list: Option<Vec<Key>> = utils::get_optional_named_arg_with_user_errors(
+        ALLOWLISTER_LIST,
+        Cep18Error::InvalidAllowlisterList,
+    );
+    let initial_balances: Option<Vec<(Key, U256)>> = utils::get_optional_named_arg_with_user_errors(
+        INITIAL_BALANCES,
+        Cep18Error::InvalidInitialBalances,
+    );
 
     let enable_mint_burn: u8 = utils::get_optional_named_arg_with_user_errors(
         ENABLE_MINT_BURN,
         Cep18Error::InvalidEnableMBFlag,
     )
     .unwrap_or(0);
+    let enable_pause: u8 = utils::get_optional_named_arg_with_user_errors(
+        ENABLE_PAUSE,
+        Cep18Error::InvalidEnablePauseFlag,
+    )
+    .unwrap_or(0);
+    Pause::try_from(enable_pause).unwrap_or_revert();
+    let enable_freeze: u8 = utils::get_optional_named_arg_with_user_errors(
+        ENABLE_FREEZE,
+        Cep18Error::InvalidEnableFreezeFlag,
+    )
+    .unwrap_or(0);
+    Freeze::try_from(enable_freeze).unwrap_or_revert();
+    let transfer_restriction: u8 = utils::get_optional_named_arg_with_user_errors(
+        TRANSFER_RESTRICTION,
+        Cep18Error::InvalidTransferRestriction,
+    )
+    .unwrap_or(0);
+    TransferRestriction::try_from(transfer_restriction).unwrap_or_revert();
+    let batch_transfer_events: u8 = utils::get_optional_named_arg_with_user_errors(
+        BATCH_TRANSFER_EVENTS,
+        Cep18Error::InvalidBatchTransferEvents,
+    )
+    .unwrap_or(0);
+    BatchTransferEvents::try_from(batch_transfer_events).unwrap_or_revert();
+    let transfer_fee: u8 = utils::get_optional_named_arg_with_user_errors(
+        TRANSFER_FEE,
+        Cep18Error::InvalidTransferFee,
+    )
+    .unwrap_or(0);
+    TransferFee::try_from(transfer_fee).unwrap_or_revert();
+    let max_fee_rate: u32 =
+        utils::get_optional_named_arg_with_user_errors(MAX_FEE_RATE, Cep18Error::InvalidMaxFeeRate)
+            .unwrap_or(0);
+    if max_fee_rate > transfer_fee::FEE_RATE_DENOMINATOR {
+        revert(Cep18Error::InvalidMaxFeeRate);
+    }
+    let fee_rate: u32 =
+        utils::get_optional_named_arg_with_user_errors(FEE_RATE, Cep18Error::InvalidFeeRate)
+            .unwrap_or(0);
+    if fee_rate > max_fee_rate {
+        revert(Cep18Error::InvalidFeeRate);
+    }
+    let treasury: Key =
+        utils::get_optional_named_arg_with_user_errors(TREASURY, Cep18Error::InvalidTreasury)
+            .unwrap_or_else(|| get_caller().into());
+    let fee_exempt_list: Option<Vec<Key>> = utils::get_optional_named_arg_with_user_errors(
+        FEE_EXEMPT_LIST,
+        Cep18Error::InvalidFeeExemptList,
+    );
+    let enable_flash_mint: u8 = utils::get_optional_named_arg_with_user_errors(
+        ENABLE_FLASH_MINT,
+        Cep18Error::InvalidFlashMint,
+    )
+    .unwrap_or(0);
+    modalities::FlashMint::try_from(enable_flash_mint).unwrap_or_revert();
+    let flash_fee_rate: u32 = utils::get_optional_named_arg_with_user_errors(
+        FLASH_FEE_RATE,
+        Cep18Error::InvalidFlashFeeRate,
+    )
+    .unwrap_or(0);
+    if flash_fee_rate > transfer_fee::FEE_RATE_DENOMINATOR {
+        revert(Cep18Error::InvalidFlashFeeRate);
+    }
+    let enable_vesting: u8 = utils::get_optional_named_arg_with_user_errors(
+        ENABLE_VESTING,
+        Cep18Error::InvalidEnableVestingFlag,
+    )
+    .unwrap_or(0);
+    Vesting::try_from(enable_vesting).unwrap_or_revert();
+    let mint_rate_limit: U256 = utils::get_optional_named_arg_with_user_errors(
+        MINT_RATE_LIMIT,
+        Cep18Error::InvalidMintRateLimit,
+    )
+    .unwrap_or_default();
+    let mint_rate_window: u64 = utils::get_optional_named_arg_with_user_errors(
+        MINT_RATE_WINDOW,
+        Cep18Error::InvalidMintRateWindow,
+    )
+    .unwrap_or_default();
 
     let mut named_keys = NamedKeys::new();
     named_keys.insert(NAME.to_string(), storage::new_uref(name).into());
@@ -417,6 +1492,7 @@ pub fn install_contract(name: &str) {
         TOTAL_SUPPLY.to_string(),
         storage::new_uref(total_supply).into(),
     );
+    named_keys.insert(MAX_SUPPLY.to_string(), storage::new_uref(max_supply).into());
     named_keys.insert(
         EVENTS_MODE.to_string(),
         storage::new_uref(events_mode).into(),
@@ -425,6 +1501,54 @@ pub fn install_contract(name: &str) {
         ENABLE_MINT_BURN.to_string(),
         storage::new_uref(enable_mint_burn).into(),
     );
+    named_keys.insert(
+        ENABLE_PAUSE.to_string(),
+        storage::new_uref(enable_pause).into(),
+    );
+    named_keys.insert(PAUSED.to_string(), storage::new_uref(false).into());
+    named_keys.insert(ADMIN_COUNT.to_string(), storage::new_uref(0u32).into());
+    named_keys.insert(
+        TRANSFER_FEE.to_string(),
+        storage::new_uref(transfer_fee).into(),
+    );
+    named_keys.insert(FEE_RATE.to_string(), storage::new_uref(fee_rate).into());
+    named_keys.insert(
+        MAX_FEE_RATE.to_string(),
+        storage::new_uref(max_fee_rate).into(),
+    );
+    named_keys.insert(TREASURY.to_string(), storage::new_uref(treasury).into());
+    named_keys.insert(
+        MINT_RATE_LIMIT.to_string(),
+        storage::new_uref(mint_rate_limit).into(),
+    );
+    named_keys.insert(
+        MINT_RATE_WINDOW.to_string(),
+        storage::new_uref(mint_rate_window).into(),
+    );
+    named_keys.insert(
+        ENABLE_FREEZE.to_string(),
+        storage::new_uref(enable_freeze).into(),
+    );
+    named_keys.insert(
+        TRANSFER_RESTRICTION.to_string(),
+        storage::new_uref(transfer_restriction).into(),
+    );
+    named_keys.insert(
+        BATCH_TRANSFER_EVENTS.to_string(),
+        storage::new_uref(batch_transfer_events).into(),
+    );
+    named_keys.insert(
+        ENABLE_FLASH_MINT.to_string(),
+        storage::new_uref(enable_flash_mint).into(),
+    );
+    named_keys.insert(
+        FLASH_FEE_RATE.to_string(),
+        storage::new_uref(flash_fee_rate).into(),
+    );
+    named_keys.insert(
+        ENABLE_VESTING.to_string(),
+        storage::new_uref(enable_vesting).into(),
+    );
     let entry_points = generate_entry_points();
 
     let hash_key_name = format!("{HASH_KEY_NAME_PREFIX}{name}");
@@ -457,6 +1581,36 @@ pub fn install_contract(name: &str) {
             .insert(MINTER_LIST, minter_list)
             .unwrap_or_revert();
     }
+    if let Some(burner_list) = burner_list {
+        init_args
+            .insert(BURNER_LIST, burner_list)
+            .unwrap_or_revert();
+    }
+    if let Some(mint_and_burn_list) = mint_and_burn_list {
+        init_args
+            .insert(MINT_AND_BURN_LIST, mint_and_burn_list)
+            .unwrap_or_revert();
+    }
+    if let Some(pauser_list) = pauser_list {
+        init_args
+            .insert(PAUSER_LIST, pauser_list)
+            .unwrap_or_revert();
+    }
+    if let Some(allowlister_list) = allowlister_list {
+        init_args
+            .insert(ALLOWLISTER_LIST, allowlister_list)
+            .unwrap_or_revert();
+    }
+    if let Some(fee_exempt_list) = fee_exempt_list {
+        init_args
+            .insert(FEE_EXEMPT_LIST, fee_exempt_list)
+            .unwrap_or_revert();
+    }
+    if let Some(initial_balances) = initial_balances {
+        init_args
+            .insert(INITIAL_BALANCES, initial_balances)
+            .unwrap_or_revert();
+    }
 
     runtime::call_contract::<()>(contract_hash, INIT_ENTRY_POINT_NAME, init_args);
 }
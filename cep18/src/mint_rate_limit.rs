@@ -0,0 +1,102 @@
+//! Implementation of per-minter mint rate limits.
+//!
+//! The amount a minter minted over the last window is tracked in `MINT_BUCKETS_PER_WINDOW`
+//! buckets, each covering an equal slice of the window, so that amounts leave the window slice by
+//! slice instead of all at once.
+use alloc::{format, string::String};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{Key, U256};
+
+use crate::{
+    constants::{MINT_BUCKETS, MINT_RATE_LIMIT, MINT_RATE_WINDOW},
+    error::Cep18Error,
+    roles,
+    utils::{
+        get_dictionary_uref, make_dictionary_item_key, read_optional_from, write_named_value,
+        SecurityBadge,
+    },
+};
+
+/// Number of buckets a window is split into.
+const MINT_BUCKETS_PER_WINDOW: u64 = 10;
+
+/// Writes the maximum amount a minter can mint per window and the window length in
+/// milliseconds. A window of `0` disables the limit.
+pub(crate) fn write_mint_rate_limit(limit: U256, window: u64) {
+    write_named_value(MINT_RATE_LIMIT, limit);
+    write_named_value(MINT_RATE_WINDOW, window);
+}
+
+/// Reads the mint rate limit as `(limit, window)`, `None` meaning minting is not rate limited.
+pub(crate) fn read_mint_rate_limit() -> Option<(U256, u64)> {
+    let window = read_optional_from::<u64>(MINT_RATE_WINDOW).unwrap_or_default();
+    if window == 0 {
+        return None;
+    }
+    Some((
+        read_optional_from(MINT_RATE_LIMIT).unwrap_or_default(),
+        window,
+    ))
+}
+
+/// Each minter has `MINT_BUCKETS_PER_WINDOW` slots, which hold the bucket index and the amount
+/// minted in it as `(bucket, minted)`. A bucket reuses the slot of the bucket a window before it.
+fn make_mint_bucket_item_key(slot: u64, minter_item_key: &str) -> String {
+    format!("{}_{}", slot, minter_item_key)
+}
+
+/// Records a minted amount against the rolling window of a minter.
+///
+/// The limit applies to the amount minted over the last window, measured in whole buckets: the
+/// current bucket and the `MINT_BUCKETS_PER_WINDOW - 1` before it. Admins are not limited.
+/// Once the window is widened, buckets recorded with the former window can be ahead of the
+/// current bucket: they no longer count, so that a minter is never limited past a whole window.
+pub(crate) fn record_mint(minter: Key, amount: U256) -> Result<(), Cep18Error> {
+    let (limit, window) = match read_mint_rate_limit() {
+        Some(rate_limit) => rate_limit,
+        None => return Ok(()),
+    };
+    if roles::read_roles(minter).contains(SecurityBadge::Admin) {
+        return Ok(());
+    }
+    let now: u64 = runtime::get_blocktime().into();
+    let bucket_width = window.saturating_add(MINT_BUCKETS_PER_WINDOW - 1) / MINT_BUCKETS_PER_WINDOW;
+    let current_bucket = now / bucket_width;
+    let oldest_bucket = current_bucket.saturating_sub(MINT_BUCKETS_PER_WINDOW - 1);
+
+    let mint_buckets_uref = get_dictionary_uref(MINT_BUCKETS);
+    let minter_item_key = make_dictionary_item_key(minter);
+    let mut minted = amount;
+    let mut minted_in_current_bucket = U256::zero();
+    for slot in 0..MINT_BUCKETS_PER_WINDOW {
+        let (bucket, bucket_minted) = storage::dictionary_get::<(u64, U256)>(
+            mint_buckets_uref,
+            &make_mint_bucket_item_key(slot, &minter_item_key),
+        )
+        .unwrap_or_revert()
+        .unwrap_or_default();
+        if bucket < oldest_bucket || bucket > current_bucket {
+            continue;
+        }
+        if bucket == current_bucket {
+            minted_in_current_bucket = bucket_minted;
+        }
+        minted = minted
+            .checked_add(bucket_minted)
+            .ok_or(Cep18Error::ExceedsMintRateLimit)?;
+    }
+    if minted > limit {
+        return Err(Cep18Error::ExceedsMintRateLimit);
+    }
+    // Cannot overflow, the amounts minted in all buckets add up to at most the limit.
+    storage::dictionary_put(
+        mint_buckets_uref,
+        &make_mint_bucket_item_key(current_bucket % MINT_BUCKETS_PER_WINDOW, &minter_item_key),
+        (current_bucket, minted_in_current_bucket + amount),
+    );
+    Ok(())
+}
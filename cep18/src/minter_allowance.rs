@@ -0,0 +1,55 @@
+//! Implementation of per-minter mint quotas.
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{Key, U256};
+
+use crate::{
+    constants::MINTER_ALLOWANCE,
+    error::Cep18Error,
+    roles,
+    utils::{get_dictionary_uref, make_dictionary_item_key, SecurityBadge},
+};
+
+/// Writes the remaining mint quota of a specified minter into a dictionary. `None` lifts the
+/// quota.
+pub(crate) fn write_minter_allowance_to(minter: Key, allowance: Option<U256>) {
+    let dictionary_item_key = make_dictionary_item_key(minter);
+    storage::dictionary_put(
+        get_dictionary_uref(MINTER_ALLOWANCE),
+        &dictionary_item_key,
+        allowance,
+    );
+}
+
+/// Reads the remaining mint quota of a specified minter.
+///
+/// Minters without a quota are not present in the dictionary and read as `None`, meaning they can
+/// mint without limit.
+pub(crate) fn read_minter_allowance_from(minter: Key) -> Option<U256> {
+    let minter_allowance_uref = match runtime::get_key(MINTER_ALLOWANCE) {
+        Some(key) => key.into_uref().unwrap_or_revert(),
+        None => return None,
+    };
+    let dictionary_item_key = make_dictionary_item_key(minter);
+    storage::dictionary_get::<Option<U256>>(minter_allowance_uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .flatten()
+}
+
+/// Deducts a minted amount from the quota of a minter.
+///
+/// Admins and minters without a quota are not limited.
+pub(crate) fn spend_minter_allowance(minter: Key, amount: U256) -> Result<(), Cep18Error> {
+    if roles::read_roles(minter).contains(SecurityBadge::Admin) {
+        return Ok(());
+    }
+    if let Some(allowance) = read_minter_allowance_from(minter) {
+        let new_allowance = allowance
+            .checked_sub(amount)
+            .ok_or(Cep18Error::ExceedsMinterAllowance)?;
+        write_minter_allowance_to(minter, Some(new_allowance));
+    }
+    Ok(())
+}
@@ -41,3 +41,136 @@ impl TryFrom<u8> for MintBurn {
         }
     }
 }
+
+#[repr(u8)]
+#[derive(PartialEq, Eq)]
+pub enum Pause {
+    Disabled = 0,
+    Pausable = 1,
+}
+
+impl TryFrom<u8> for Pause {
+    type Error = Cep18Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Pause::Disabled),
+            1 => Ok(Pause::Pausable),
+            _ => Err(Cep18Error::InvalidEnablePauseFlag),
+        }
+    }
+}
+
+#[repr(u8)]
+#[derive(PartialEq, Eq)]
+pub enum Freeze {
+    Disabled = 0,
+    Freezable = 1,
+}
+
+impl TryFrom<u8> for Freeze {
+    type Error = Cep18Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Freeze::Disabled),
+            1 => Ok(Freeze::Freezable),
+            _ => Err(Cep18Error::InvalidEnableFreezeFlag),
+        }
+    }
+}
+
+#[repr(u8)]
+#[derive(PartialEq, Eq)]
+pub enum TransferRestriction {
+    Unrestricted = 0,
+    Allowlist = 1,
+}
+
+impl TryFrom<u8> for TransferRestriction {
+    type Error = Cep18Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(TransferRestriction::Unrestricted),
+            1 => Ok(TransferRestriction::Allowlist),
+            _ => Err(Cep18Error::InvalidTransferRestriction),
+        }
+    }
+}
+
+#[repr(u8)]
+#[derive(PartialEq, Eq)]
+pub enum BatchTransferEvents {
+    PerTransfer = 0,
+    Batch = 1,
+}
+
+impl TryFrom<u8> for BatchTransferEvents {
+    type Error = Cep18Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(BatchTransferEvents::PerTransfer),
+            1 => Ok(BatchTransferEvents::Batch),
+            _ => Err(Cep18Error::InvalidBatchTransferEvents),
+        }
+    }
+}
+
+#[repr(u8)]
+#[derive(PartialEq, Eq)]
+pub enum TransferFee {
+    Disabled = 0,
+    Enabled = 1,
+}
+
+impl TryFrom<u8> for TransferFee {
+    type Error = Cep18Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(TransferFee::Disabled),
+            1 => Ok(TransferFee::Enabled),
+            _ => Err(Cep18Error::InvalidTransferFee),
+        }
+    }
+}
+
+#[repr(u8)]
+#[derive(PartialEq, Eq)]
+pub enum FlashMint {
+    Disabled = 0,
+    Enabled = 1,
+}
+
+impl TryFrom<u8> for FlashMint {
+    type Error = Cep18Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(FlashMint::Disabled),
+            1 => Ok(FlashMint::Enabled),
+            _ => Err(Cep18Error::InvalidFlashMint),
+        }
+    }
+}
+
+#[repr(u8)]
+#[derive(PartialEq, Eq)]
+pub enum Vesting {
+    Disabled = 0,
+    Enabled = 1,
+}
+
+impl TryFrom<u8> for Vesting {
+    type Error = Cep18Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Vesting::Disabled),
+            1 => Ok(Vesting::Enabled),
+            _ => Err(Cep18Error::InvalidEnableVestingFlag),
+        }
+    }
+}
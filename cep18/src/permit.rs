@@ -0,0 +1,81 @@
+//! Implementation of signed approvals (`permit`).
+use alloc::vec::Vec;
+use core::convert::TryInto;
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::{Bytes, ToBytes},
+    crypto, Key, PublicKey, Signature, U256,
+};
+
+use crate::{
+    constants::{NONCES, PACKAGE_HASH, PERMIT_MESSAGE_PREFIX},
+    error::Cep18Error,
+    utils::{get_dictionary_uref, make_dictionary_item_key},
+};
+
+/// Writes the next permit nonce of a specified owner into a dictionary.
+pub(crate) fn write_nonce_to(owner: Key, nonce: u64) {
+    let dictionary_item_key = make_dictionary_item_key(owner);
+    storage::dictionary_put(get_dictionary_uref(NONCES), &dictionary_item_key, nonce);
+}
+
+/// Reads the next permit nonce of a specified owner.
+///
+/// Owners that never used a permit read as zero.
+pub(crate) fn read_nonce_from(owner: Key) -> u64 {
+    let nonces_uref = match runtime::get_key(NONCES) {
+        Some(key) => key.into_uref().unwrap_or_revert(),
+        None => return 0,
+    };
+    let dictionary_item_key = make_dictionary_item_key(owner);
+    storage::dictionary_get(nonces_uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Builds the message an owner signs to grant a `permit`.
+///
+/// The message is the blake2b hash of the concatenated serialized `PERMIT_MESSAGE_PREFIX`
+/// bytes, token package hash, owner public key, spender, amount, deadline and nonce. The package
+/// hash binds the signature to this token.
+pub(crate) fn make_permit_message(
+    owner: &PublicKey,
+    spender: Key,
+    amount: U256,
+    deadline: u64,
+    nonce: u64,
+) -> [u8; 32] {
+    let package_hash: Key = runtime::get_key(PACKAGE_HASH).unwrap_or_revert();
+    let mut preimage = Vec::new();
+    preimage.extend_from_slice(PERMIT_MESSAGE_PREFIX.as_bytes());
+    preimage.append(&mut package_hash.to_bytes().unwrap_or_revert());
+    preimage.append(&mut owner.to_bytes().unwrap_or_revert());
+    preimage.append(&mut spender.to_bytes().unwrap_or_revert());
+    preimage.append(&mut amount.to_bytes().unwrap_or_revert());
+    preimage.append(&mut deadline.to_bytes().unwrap_or_revert());
+    preimage.append(&mut nonce.to_bytes().unwrap_or_revert());
+    runtime::blake2b(preimage)
+}
+
+/// Verifies a raw 64-byte signature of `message` made with the owner's key.
+pub(crate) fn verify_signature(
+    owner: &PublicKey,
+    message: &[u8],
+    signature: &Bytes,
+) -> Result<(), Cep18Error> {
+    let raw_signature: [u8; 64] = signature
+        .as_slice()
+        .try_into()
+        .map_err(|_| Cep18Error::InvalidSignature)?;
+    let signature = match owner {
+        PublicKey::Ed25519(_) => Signature::ed25519(raw_signature),
+        PublicKey::Secp256k1(_) => Signature::secp256k1(raw_signature),
+        _ => return Err(Cep18Error::InvalidSignature),
+    }
+    .map_err(|_| Cep18Error::InvalidSignature)?;
+    crypto::verify(message, &signature, owner).map_err(|_| Cep18Error::InvalidSignature)
+}
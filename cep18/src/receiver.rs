@@ -0,0 +1,92 @@
+//! Implementation of the receiver hook of contracts receiving tokens.
+//!
+//! Receiver contracts expose a public `on_cep18_received` entry point taking the `sender` (`Key`),
+//! the `amount` received (`U256`) and the `data` (`Bytes`) passed along, and returning whether they
+//! accept the tokens (`bool`).
+//!
+//! Contracts cannot inspect the entry points of another contract, so receiver contracts able to
+//! move the tokens they hold opt into safe transfers by calling `register_receiver`, and opt out by
+//! calling `unregister_receiver`. Safe transfers to a registered contract also call its hook.
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{bytesrepr::Bytes, runtime_args, ContractPackageHash, Key, RuntimeArgs, U256};
+
+use crate::{
+    constants::{AMOUNT, DATA, ON_CEP18_RECEIVED_ENTRY_POINT_NAME, REGISTERED_RECEIVERS, SENDER},
+    error::Cep18Error,
+    utils::{get_dictionary_uref, make_dictionary_item_key},
+};
+
+/// Returns the contract package of a recipient, which must be a `Key::Hash`.
+pub(crate) fn receiver_package(recipient: Key) -> Result<ContractPackageHash, Cep18Error> {
+    recipient
+        .into_hash()
+        .map(ContractPackageHash::new)
+        .ok_or(Cep18Error::InvalidReceiver)
+}
+
+/// Calls the `on_cep18_received` hook of the latest version of a receiver contract, after
+/// `amount` tokens were received from the `sender`.
+///
+/// A hook reverting reverts the whole execution, and a hook returning `false` rejects the tokens.
+pub(crate) fn call_on_received(
+    receiver: ContractPackageHash,
+    sender: Key,
+    amount: U256,
+    data: Bytes,
+) -> Result<(), Cep18Error> {
+    let accepted: bool = runtime::call_versioned_contract(
+        receiver,
+        None,
+        ON_CEP18_RECEIVED_ENTRY_POINT_NAME,
+        runtime_args! {
+            SENDER => sender,
+            AMOUNT => amount,
+            DATA => data,
+        },
+    );
+    if !accepted {
+        return Err(Cep18Error::TransferRejected);
+    }
+    Ok(())
+}
+
+/// Registers a contract package as able to receive tokens through safe transfers, or
+/// unregisters it.
+pub(crate) fn write_registered_receiver(receiver: ContractPackageHash, registered: bool) {
+    let dictionary_item_key = make_dictionary_item_key(Key::from(receiver));
+    storage::dictionary_put(
+        get_dictionary_uref(REGISTERED_RECEIVERS),
+        &dictionary_item_key,
+        registered,
+    );
+}
+
+/// Checks that the recipient of a safe transfer can move the tokens it would receive, returning
+/// the contract package whose hook must be called once the tokens are received.
+///
+/// Accounts can, as can contract packages registered through `register_receiver`. Any other
+/// recipient reverts with `UnsafeRecipient`.
+pub(crate) fn check_safe_recipient(
+    recipient: Key,
+) -> Result<Option<ContractPackageHash>, Cep18Error> {
+    let registered_receivers_uref = match recipient {
+        Key::Account(_) => return Ok(None),
+        Key::Hash(_) => match runtime::get_key(REGISTERED_RECEIVERS) {
+            Some(key) => key.into_uref().unwrap_or_revert(),
+            None => return Err(Cep18Error::UnsafeRecipient),
+        },
+        _ => return Err(Cep18Error::UnsafeRecipient),
+    };
+    let dictionary_item_key = make_dictionary_item_key(recipient);
+    let registered =
+        storage::dictionary_get::<bool>(registered_receivers_uref, &dictionary_item_key)
+            .unwrap_or_revert()
+            .unwrap_or_default();
+    if !registered {
+        return Err(Cep18Error::UnsafeRecipient);
+    }
+    receiver_package(recipient).map(Some)
+}
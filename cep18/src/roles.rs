@@ -0,0 +1,318 @@
+//! Implementation of the role bitmask security model.
+use alloc::{format, string::String, vec::Vec};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+
+use casper_types::Key;
+
+use crate::{
+    constants::{
+        ADMIN_COUNT, ADMIN_NOMINATIONS, ROLES, ROLE_MEMBERS, ROLE_MEMBER_INDICES, SECURITY_BADGES,
+    },
+    error::Cep18Error,
+    utils::{self, get_dictionary_uref, make_dictionary_item_key, SecurityBadge},
+};
+
+/// Set of roles held by an account, with one bit per [`SecurityBadge`].
+///
+/// `SecurityBadge::None` has no bit of its own and stands for the empty set.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Roles(u32);
+
+impl Roles {
+    fn bit(badge: SecurityBadge) -> u32 {
+        match badge {
+            SecurityBadge::None => 0,
+            badge => 1 << badge as u8,
+        }
+    }
+
+    /// Returns `true` if the set holds the given role.
+    pub fn contains(&self, badge: SecurityBadge) -> bool {
+        self.0 & Self::bit(badge) != 0
+    }
+
+    /// Returns `true` if the set holds any of the given roles.
+    pub fn intersects(&self, badges: &[SecurityBadge]) -> bool {
+        badges.iter().any(|badge| self.contains(*badge))
+    }
+
+    /// Adds a role to the set.
+    pub fn insert(&mut self, badge: SecurityBadge) {
+        self.0 |= Self::bit(badge);
+    }
+
+    /// Removes a role from the set.
+    pub fn remove(&mut self, badge: SecurityBadge) {
+        self.0 &= !Self::bit(badge);
+    }
+
+    /// Returns the strongest role of the set following the change strength of `change_security`,
+    /// or `SecurityBadge::None` for the empty set.
+    pub fn badge(&self) -> SecurityBadge {
+        [
+            SecurityBadge::Admin,
+            SecurityBadge::Allowlister,
+            SecurityBadge::Pauser,
+            SecurityBadge::MintAndBurn,
+            SecurityBadge::Burner,
+            SecurityBadge::Minter,
+        ]
+        .iter()
+        .copied()
+        .find(|badge| self.contains(*badge))
+        .unwrap_or(SecurityBadge::None)
+    }
+}
+
+/// Roles whose members are indexed for `get_role_members`.
+const INDEXED_ROLES: [SecurityBadge; 6] = [
+    SecurityBadge::Admin,
+    SecurityBadge::Minter,
+    SecurityBadge::Pauser,
+    SecurityBadge::Allowlister,
+    SecurityBadge::Burner,
+    SecurityBadge::MintAndBurn,
+];
+
+impl From<SecurityBadge> for Roles {
+    fn from(badge: SecurityBadge) -> Self {
+        Roles(Self::bit(badge))
+    }
+}
+
+/// Writes the roles of a specified account into a dictionary, keeping the admin count and the
+/// role member index in step.
+pub(crate) fn write_roles(account: Key, roles: Roles) {
+    let was_admin = read_roles(account).contains(SecurityBadge::Admin);
+    let is_admin = roles.contains(SecurityBadge::Admin);
+    if was_admin != is_admin {
+        update_admin_count(is_admin);
+    }
+    let dictionary_item_key = make_dictionary_item_key(account);
+    // Roles only held through the legacy badge were never indexed.
+    let indexed_roles: Roles = match runtime::get_key(ROLES) {
+        Some(key) => {
+            storage::dictionary_get::<u32>(key.into_uref().unwrap_or_revert(), &dictionary_item_key)
+                .unwrap_or_revert()
+                .map(Roles)
+                .unwrap_or_default()
+        }
+        None => Roles::default(),
+    };
+    for badge in INDEXED_ROLES {
+        match (indexed_roles.contains(badge), roles.contains(badge)) {
+            (false, true) => add_role_member(badge, account),
+            (true, false) => remove_role_member(badge, account),
+            _ => {}
+        }
+    }
+    storage::dictionary_put(get_dictionary_uref(ROLES), &dictionary_item_key, roles.0);
+}
+
+/// Reads the roles of a specified account.
+///
+/// Accounts without an entry in the "roles" dictionary fall back to the single badge they hold in
+/// the legacy "security_badges" dictionary, if any.
+pub(crate) fn read_roles(account: Key) -> Roles {
+    let dictionary_item_key = make_dictionary_item_key(account);
+    if let Some(key) = runtime::get_key(ROLES) {
+        let roles_uref = key.into_uref().unwrap_or_revert();
+        if let Some(bits) =
+            storage::dictionary_get::<u32>(roles_uref, &dictionary_item_key).unwrap_or_revert()
+        {
+            return Roles(bits);
+        }
+    }
+    match runtime::get_key(SECURITY_BADGES) {
+        Some(key) => storage::dictionary_get::<SecurityBadge>(
+            key.into_uref().unwrap_or_revert(),
+            &dictionary_item_key,
+        )
+        .unwrap_or_revert()
+        .map(Roles::from)
+        .unwrap_or_default(),
+        None => Roles::default(),
+    }
+}
+
+/// Adds a role to a specified account.
+pub(crate) fn grant_role(account: Key, badge: SecurityBadge) {
+    let mut roles = read_roles(account);
+    roles.insert(badge);
+    write_roles(account, roles);
+}
+
+/// Removes a role from a specified account.
+pub(crate) fn revoke_role(account: Key, badge: SecurityBadge) {
+    let mut roles = read_roles(account);
+    roles.remove(badge);
+    write_roles(account, roles);
+}
+
+/// Increments or decrements the number of Admins.
+///
+/// Contracts upgraded from a version without the count do not track it.
+fn update_admin_count(increment: bool) {
+    let admin_count_uref = match runtime::get_key(ADMIN_COUNT) {
+        Some(key) => key.into_uref().unwrap_or_revert(),
+        None => return,
+    };
+    let admin_count: u32 = storage::read(admin_count_uref)
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    let admin_count = if increment {
+        admin_count.checked_add(1)
+    } else {
+        admin_count.checked_sub(1)
+    }
+    .ok_or(Cep18Error::Overflow)
+    .unwrap_or_revert();
+    storage::write(admin_count_uref, admin_count);
+}
+
+/// Returns an error if no Admin is left, which would lock out all admin functionality.
+///
+/// Contracts upgraded from a version without the admin count cannot tell whether other Admins are
+/// left, so there the calling Admin must keep its own Admin role.
+pub(crate) fn check_admin_left(caller: Key) -> Result<(), Cep18Error> {
+    match utils::read_optional_from::<u32>(ADMIN_COUNT) {
+        Some(0) => Err(Cep18Error::LastAdmin),
+        Some(_) => Ok(()),
+        None if !read_roles(caller).contains(SecurityBadge::Admin) => Err(Cep18Error::LastAdmin),
+        None => Ok(()),
+    }
+}
+
+/// Writes the Admin who nominated a specified account into a dictionary. `None` clears the
+/// nomination.
+pub(crate) fn write_admin_nomination(nominee: Key, proposer: Option<Key>) {
+    let dictionary_item_key = make_dictionary_item_key(nominee);
+    storage::dictionary_put(
+        get_dictionary_uref(ADMIN_NOMINATIONS),
+        &dictionary_item_key,
+        proposer,
+    );
+}
+
+/// Reads the Admin who nominated a specified account, if the account has a pending nomination.
+pub(crate) fn read_admin_nomination(nominee: Key) -> Option<Key> {
+    let admin_nominations_uref = match runtime::get_key(ADMIN_NOMINATIONS) {
+        Some(key) => key.into_uref().unwrap_or_revert(),
+        None => return None,
+    };
+    let dictionary_item_key = make_dictionary_item_key(nominee);
+    storage::dictionary_get::<Option<Key>>(admin_nominations_uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .flatten()
+}
+
+fn make_role_count_item_key(badge: SecurityBadge) -> String {
+    format!("{}", badge as u8)
+}
+
+fn make_role_member_item_key(badge: SecurityBadge, index: u32) -> String {
+    format!("{}_{}", badge as u8, index)
+}
+
+fn make_role_member_index_item_key(badge: SecurityBadge, account: Key) -> String {
+    format!("{}_{}", badge as u8, make_dictionary_item_key(account))
+}
+
+/// Reads the number of indexed members of a role.
+pub(crate) fn read_role_member_count(badge: SecurityBadge) -> u32 {
+    let role_members_uref = match runtime::get_key(ROLE_MEMBERS) {
+        Some(key) => key.into_uref().unwrap_or_revert(),
+        None => return 0,
+    };
+    storage::dictionary_get(role_members_uref, &make_role_count_item_key(badge))
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Appends an account to the member list of a role.
+fn add_role_member(badge: SecurityBadge, account: Key) {
+    let role_members_uref = get_dictionary_uref(ROLE_MEMBERS);
+    let count = read_role_member_count(badge);
+    storage::dictionary_put(
+        role_members_uref,
+        &make_role_member_item_key(badge, count),
+        account,
+    );
+    storage::dictionary_put(
+        get_dictionary_uref(ROLE_MEMBER_INDICES),
+        &make_role_member_index_item_key(badge, account),
+        count,
+    );
+    storage::dictionary_put(
+        role_members_uref,
+        &make_role_count_item_key(badge),
+        count + 1,
+    );
+}
+
+/// Removes an account from the member list of a role by moving the last member into its place.
+fn remove_role_member(badge: SecurityBadge, account: Key) {
+    let role_members_uref = get_dictionary_uref(ROLE_MEMBERS);
+    let role_member_indices_uref = get_dictionary_uref(ROLE_MEMBER_INDICES);
+    let index: u32 = storage::dictionary_get(
+        role_member_indices_uref,
+        &make_role_member_index_item_key(badge, account),
+    )
+    .unwrap_or_revert()
+    .unwrap_or_revert();
+    let last_index = read_role_member_count(badge) - 1;
+    if index != last_index {
+        let last_member: Key = storage::dictionary_get(
+            role_members_uref,
+            &make_role_member_item_key(badge, last_index),
+        )
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+        storage::dictionary_put(
+            role_members_uref,
+            &make_role_member_item_key(badge, index),
+            last_member,
+        );
+        storage::dictionary_put(
+            role_member_indices_uref,
+            &make_role_member_index_item_key(badge, last_member),
+            index,
+        );
+    }
+    storage::dictionary_put(
+        role_members_uref,
+        &make_role_count_item_key(badge),
+        last_index,
+    );
+}
+
+/// Indexes the roles an account only holds through its legacy badge, so that it is listed as a
+/// member of these roles. Accounts whose roles were written since the upgrade are left unchanged.
+pub(crate) fn index_legacy_roles(account: Key) {
+    let roles = read_roles(account);
+    if roles != Roles::default() {
+        write_roles(account, roles);
+    }
+}
+
+/// Reads up to `limit` members of a role, starting at `offset`.
+///
+/// The order of the members changes as accounts lose the role.
+pub(crate) fn read_role_members(badge: SecurityBadge, offset: u32, limit: u32) -> Vec<Key> {
+    let count = read_role_member_count(badge);
+    let end = offset.saturating_add(limit).min(count);
+    if offset >= end {
+        return Vec::new();
+    }
+    let role_members_uref = get_dictionary_uref(ROLE_MEMBERS);
+    (offset..end)
+        .map(|index| {
+            storage::dictionary_get(role_members_uref, &make_role_member_item_key(badge, index))
+                .unwrap_or_revert()
+                .unwrap_or_revert()
+        })
+        .collect()
+}
@@ -0,0 +1,101 @@
+//! Implementation of balance and total supply snapshots.
+//!
+//! Taking a snapshot only increments the current snapshot id. The balance of an account, or the
+//! total supply, is checkpointed on its first write after a snapshot, recording its value at the
+//! time of the snapshot.
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{Key, URef, U256};
+
+use crate::{
+    balances, checkpoints,
+    constants::{BALANCE_SNAPSHOTS, CURRENT_SNAPSHOT_ID, TOTAL_SUPPLY, TOTAL_SUPPLY_SNAPSHOTS},
+    error::Cep18Error,
+    utils::{self, make_dictionary_item_key},
+};
+
+/// Reads the id of the latest snapshot, `0` meaning no snapshot was taken yet.
+pub(crate) fn read_current_snapshot_id() -> u64 {
+    utils::read_optional_from(CURRENT_SNAPSHOT_ID).unwrap_or_default()
+}
+
+/// Takes a new snapshot and returns its id.
+///
+/// Contracts installed, or upgraded from a version, without snapshots get their snapshot state on
+/// the first snapshot.
+pub(crate) fn snapshot() -> u64 {
+    let snapshot_id = read_current_snapshot_id() + 1;
+    match runtime::get_key(CURRENT_SNAPSHOT_ID) {
+        Some(key) => storage::write(key.into_uref().unwrap_or_revert(), snapshot_id),
+        None => {
+            runtime::put_key(CURRENT_SNAPSHOT_ID, storage::new_uref(snapshot_id).into());
+            storage::new_dictionary(BALANCE_SNAPSHOTS).unwrap_or_revert();
+            storage::new_dictionary(TOTAL_SUPPLY_SNAPSHOTS).unwrap_or_revert();
+        }
+    }
+    snapshot_id
+}
+
+/// Checkpoints the current value of a list if it was not written since the latest snapshot.
+fn update_snapshot(checkpoints_name: &str, list: &str, current_value: impl FnOnce() -> U256) {
+    let snapshot_id = read_current_snapshot_id();
+    if snapshot_id == 0 {
+        return;
+    }
+    let checkpoints_uref = utils::get_uref(checkpoints_name);
+    if checkpoints::latest(checkpoints_uref, list).map_or(0, |(id, _)| id) < snapshot_id {
+        checkpoints::push(checkpoints_uref, list, snapshot_id, current_value());
+    }
+}
+
+/// Checkpoints the balance of an account before it is written.
+pub(crate) fn update_balance_snapshot(balances_uref: URef, address: Key) {
+    update_snapshot(
+        BALANCE_SNAPSHOTS,
+        &make_dictionary_item_key(address),
+        || balances::read_balance_from(balances_uref, address),
+    );
+}
+
+/// Checkpoints the total supply before it is written.
+pub(crate) fn update_total_supply_snapshot(total_supply_uref: URef) {
+    update_snapshot(TOTAL_SUPPLY_SNAPSHOTS, TOTAL_SUPPLY, || {
+        utils::read_total_supply_from(total_supply_uref)
+    });
+}
+
+/// Returns the value of a list at a given snapshot, or `None` if it was not written since.
+fn read_snapshot_value(
+    checkpoints_name: &str,
+    list: &str,
+    snapshot_id: u64,
+) -> Result<Option<U256>, Cep18Error> {
+    if snapshot_id == 0 || snapshot_id > read_current_snapshot_id() {
+        return Err(Cep18Error::InvalidSnapshotId);
+    }
+    Ok(checkpoints::upper_lookup(
+        utils::get_uref(checkpoints_name),
+        list,
+        snapshot_id,
+    ))
+}
+
+/// Reads the balance of an account at a given snapshot.
+pub(crate) fn read_balance_at(address: Key, snapshot_id: u64) -> Result<U256, Cep18Error> {
+    let balance = read_snapshot_value(
+        BALANCE_SNAPSHOTS,
+        &make_dictionary_item_key(address),
+        snapshot_id,
+    )?;
+    Ok(balance
+        .unwrap_or_else(|| balances::read_balance_from(balances::get_balances_uref(), address)))
+}
+
+/// Reads the total supply at a given snapshot.
+pub(crate) fn read_total_supply_at(snapshot_id: u64) -> Result<U256, Cep18Error> {
+    let total_supply = read_snapshot_value(TOTAL_SUPPLY_SNAPSHOTS, TOTAL_SUPPLY, snapshot_id)?;
+    Ok(total_supply
+        .unwrap_or_else(|| utils::read_total_supply_from(utils::get_total_supply_uref())))
+}
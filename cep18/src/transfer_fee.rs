@@ -0,0 +1,132 @@
+//! Implementation of the transfer fee.
+use core::convert::TryFrom;
+
+use alloc::{collections::BTreeMap, vec::Vec};
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{Key, URef, U256};
+
+use crate::{
+    balances,
+    constants::{FEE_EXEMPT, FEE_RATE, MAX_FEE_RATE, TRANSFER_FEE, TREASURY},
+    error::Cep18Error,
+    modalities::TransferFee,
+    utils::{self, make_dictionary_item_key},
+};
+
+/// Denominator of fee rates, which are expressed in basis points.
+pub(crate) const FEE_RATE_DENOMINATOR: u32 = 10_000;
+
+/// Returns `rate` basis points of `amount`, rounded down.
+pub(crate) fn apply_basis_points(amount: U256, rate: u32) -> U256 {
+    // Split the amount to compute `amount * rate / FEE_RATE_DENOMINATOR` without overflowing.
+    let rate = U256::from(rate);
+    let denominator = U256::from(FEE_RATE_DENOMINATOR);
+    amount / denominator * rate + amount % denominator * rate / denominator
+}
+
+/// Getter for the "fee_exempt" dictionary URef.
+pub(crate) fn get_fee_exempt_uref() -> URef {
+    utils::get_uref(FEE_EXEMPT)
+}
+
+/// Returns `true` if the `Enabled` transfer fee modality was selected at install.
+pub(crate) fn transfer_fee_enabled() -> bool {
+    let transfer_fee = utils::read_optional_from::<u8>(TRANSFER_FEE).unwrap_or_default();
+    TransferFee::try_from(transfer_fee).unwrap_or_revert() == TransferFee::Enabled
+}
+
+/// Reads the current fee rate in basis points.
+pub(crate) fn read_fee_rate() -> u32 {
+    utils::read_from(FEE_RATE)
+}
+
+/// Writes the fee rate in basis points, which must not exceed the maximum fee rate set at install.
+pub(crate) fn write_fee_rate(fee_rate: u32) -> Result<(), Cep18Error> {
+    if fee_rate > utils::read_from::<u32>(MAX_FEE_RATE) {
+        return Err(Cep18Error::InvalidFeeRate);
+    }
+    storage::write(utils::get_uref(FEE_RATE), fee_rate);
+    Ok(())
+}
+
+/// Reads the account receiving the transfer fees.
+pub(crate) fn read_treasury() -> Key {
+    utils::read_from(TREASURY)
+}
+
+/// Writes the account receiving the transfer fees.
+pub(crate) fn write_treasury(treasury: Key) {
+    storage::write(utils::get_uref(TREASURY), treasury);
+}
+
+/// Reads whether a specified account is exempt from transfer fees.
+pub(crate) fn read_fee_exempt_from(fee_exempt_uref: URef, address: Key) -> bool {
+    let dictionary_item_key = make_dictionary_item_key(address);
+    storage::dictionary_get(fee_exempt_uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Adds or removes accounts from the fee exemption list.
+pub(crate) fn change_fee_exemption(fee_exemption_change_map: &BTreeMap<Key, bool>) {
+    let fee_exempt_uref = get_fee_exempt_uref();
+    for (&address, &exempt) in fee_exemption_change_map {
+        storage::dictionary_put(fee_exempt_uref, &make_dictionary_item_key(address), exempt);
+    }
+}
+
+/// Returns the fee charged on a transfer of `amount` from `sender` to `recipient`, rounded down.
+///
+/// No fee is charged when the modality is disabled, when either party is exempt or when either
+/// party is the treasury.
+pub(crate) fn compute_fee(sender: Key, recipient: Key, amount: U256) -> U256 {
+    if !transfer_fee_enabled() {
+        return U256::zero();
+    }
+    let treasury = read_treasury();
+    if sender == treasury || recipient == treasury {
+        return U256::zero();
+    }
+    let fee_exempt_uref = get_fee_exempt_uref();
+    if read_fee_exempt_from(fee_exempt_uref, sender)
+        || read_fee_exempt_from(fee_exempt_uref, recipient)
+    {
+        return U256::zero();
+    }
+    apply_basis_points(amount, read_fee_rate())
+}
+
+/// Transfers tokens from the `sender` to each of the `(recipient, amount)` pairs, routing the fee
+/// of each transfer to the treasury. The treasury is credited whether it is frozen or allowlisted.
+///
+/// Returns the `(recipient, amount)` pairs actually received and the total fee charged. As with
+/// [`balances::transfer_balances`], the sender is not validated.
+pub(crate) fn transfer_balances_with_fee(
+    sender: Key,
+    transfers: &[(Key, U256)],
+) -> Result<(Vec<(Key, U256)>, U256), Cep18Error> {
+    let mut total_fee = U256::zero();
+    let mut net_transfers = Vec::with_capacity(transfers.len());
+    for &(recipient, amount) in transfers {
+        let fee = compute_fee(sender, recipient, amount);
+        total_fee = total_fee.checked_add(fee).ok_or(Cep18Error::Overflow)?;
+        net_transfers.push((recipient, amount - fee));
+    }
+    balances::transfer_balances(sender, &net_transfers)?;
+    if !total_fee.is_zero() {
+        balances::transfer_fee_balance(sender, read_treasury(), total_fee)?;
+    }
+    Ok((net_transfers, total_fee))
+}
+
+/// Transfers `amount` from the `sender` to the `recipient`, routing the fee to the treasury.
+///
+/// Returns the amount received by the recipient and the fee charged.
+pub(crate) fn transfer_balance_with_fee(
+    sender: Key,
+    recipient: Key,
+    amount: U256,
+) -> Result<(U256, U256), Cep18Error> {
+    let (net_transfers, fee) = transfer_balances_with_fee(sender, &[(recipient, amount)])?;
+    Ok((net_transfers[0].1, fee))
+}
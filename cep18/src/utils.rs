@@ -1,12 +1,12 @@
 //! Implementation details.
-use core::convert::TryInto;
+use core::convert::{TryFrom, TryInto};
 
-use alloc::{collections::BTreeMap, vec, vec::Vec};
+use alloc::{collections::BTreeMap, string::String, vec, vec::Vec};
 use casper_contract::{
     contract_api::{
         self,
         runtime::{self, revert},
-        storage::{self, dictionary_get, dictionary_put},
+        storage,
     },
     ext_ffi,
     unwrap_or_revert::UnwrapOrRevert,
@@ -19,8 +19,11 @@ use casper_types::{
 };
 
 use crate::{
-    constants::{SECURITY_BADGES, TOTAL_SUPPLY},
+    constants::{ENABLE_MINT_BURN, ENABLE_PAUSE, MAX_SUPPLY, PAUSED, TOTAL_SUPPLY},
     error::Cep18Error,
+    modalities::Pause,
+    roles::{self, Roles},
+    snapshots,
 };
 
 /// Gets [`URef`] under a name.
@@ -41,6 +44,76 @@ where
     value
 }
 
+/// Reads value from a named key, returning `None` if the named key does not exist.
+///
+/// Contracts upgraded from an earlier version do not have the named keys introduced later on, so
+/// settings added since are read through this function and fall back to their defaults.
+pub(crate) fn read_optional_from<T>(name: &str) -> Option<T>
+where
+    T: FromBytes + CLTyped,
+{
+    let key = runtime::get_key(name)?;
+    let uref: URef = key.try_into().unwrap_or_revert();
+    Some(storage::read(uref).unwrap_or_revert().unwrap_or_revert())
+}
+
+/// Gets the [`URef`] of a dictionary, creating the dictionary if it does not exist.
+///
+/// Contracts upgraded from an earlier version do not have the dictionaries introduced later on, so
+/// those are created on first use.
+pub(crate) fn get_dictionary_uref(name: &str) -> URef {
+    match runtime::get_key(name) {
+        Some(key) => key.into_uref().unwrap_or_revert(),
+        None => storage::new_dictionary(name).unwrap_or_revert(),
+    }
+}
+
+/// Writes value to a named key, creating the named key if it does not exist.
+pub(crate) fn write_named_value<T: CLTyped + ToBytes>(name: &str, value: T) {
+    match runtime::get_key(name) {
+        Some(key) => storage::write(key.into_uref().unwrap_or_revert(), value),
+        None => runtime::put_key(name, storage::new_uref(value).into()),
+    }
+}
+
+/// Reverts with [`Cep18Error::MintBurnDisabled`] unless minting and burning were enabled at
+/// install.
+pub(crate) fn require_mint_burn_enabled() {
+    if 0 == read_from::<u8>(ENABLE_MINT_BURN) {
+        revert(Cep18Error::MintBurnDisabled)
+    }
+}
+
+/// Reverts with [`Cep18Error::PauseDisabled`] unless the `Pausable` modality was selected at
+/// install.
+pub(crate) fn require_pause_enabled() {
+    let enable_pause = read_optional_from::<u8>(ENABLE_PAUSE).unwrap_or_default();
+    if Pause::try_from(enable_pause).unwrap_or_revert() != Pause::Pausable {
+        revert(Cep18Error::PauseDisabled)
+    }
+}
+
+/// Reverts with [`Cep18Error::ContractPaused`] if the contract is currently paused.
+pub(crate) fn require_not_paused() {
+    if read_optional_from::<bool>(PAUSED).unwrap_or_default() {
+        revert(Cep18Error::ContractPaused)
+    }
+}
+
+/// Creates a dictionary item key for a dictionary item, by base64 encoding the Key argument
+/// since stringified Keys are too long to be used as dictionary keys.
+#[inline]
+pub(crate) fn make_dictionary_item_key(owner: Key) -> String {
+    let preimage = owner.to_bytes().unwrap_or_revert();
+    // NOTE: As for now dictionary item keys are limited to 64 characters only. Instead of using
+    // hashing (which will effectively hash a hash) we'll use base64. Preimage is 33 bytes for
+    // both used Key variants, and approximated base64-encoded length will be 4 * (33 / 3) ~ 44
+    // characters.
+    // Even if the preimage increased in size we still have extra space but even in case of much
+    // larger preimage we can switch to base85 which has ratio of 4:5.
+    base64::encode(preimage)
+}
+
 /// Returns address based on a [`CallStackElement`].
 ///
 /// For `Session` and `StoredSession` variants it will return account hash, and for `StoredContract`
@@ -82,10 +155,19 @@ pub(crate) fn read_total_supply_from(uref: URef) -> U256 {
 }
 
 /// Writes a total supply to a specific [`URef`].
+///
+/// The previous total supply is checkpointed first if a snapshot was taken since it was last
+/// written.
 pub(crate) fn write_total_supply_to(uref: URef, value: U256) {
+    snapshots::update_total_supply_snapshot(uref);
     storage::write(uref, value);
 }
 
+/// Reads the maximum supply, which is unbounded (`U256::MAX`) unless set at install.
+pub(crate) fn read_max_supply() -> U256 {
+    read_optional_from(MAX_SUPPLY).unwrap_or(U256::MAX)
+}
+
 pub fn get_named_arg_size(name: &str) -> Option<usize> {
     let mut arg_size: usize = 0;
     let ret = unsafe {
@@ -149,6 +231,10 @@ pub enum SecurityBadge {
     Admin = 0,
     Minter = 1,
     None = 2,
+    Pauser = 3,
+    Allowlister = 4,
+    Burner = 5,
+    MintAndBurn = 6,
 }
 
 impl CLTyped for SecurityBadge {
@@ -170,38 +256,41 @@ impl ToBytes for SecurityBadge {
 impl FromBytes for SecurityBadge {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
         Ok((
-            match bytes[0] {
-                0 => SecurityBadge::Admin,
-                1 => SecurityBadge::Minter,
-                2 => SecurityBadge::None,
-                _ => return Err(bytesrepr::Error::LeftOverBytes),
-            },
+            SecurityBadge::try_from(bytes[0]).map_err(|_| bytesrepr::Error::LeftOverBytes)?,
             &[],
         ))
     }
 }
 
+impl TryFrom<u8> for SecurityBadge {
+    type Error = Cep18Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(SecurityBadge::Admin),
+            1 => Ok(SecurityBadge::Minter),
+            2 => Ok(SecurityBadge::None),
+            3 => Ok(SecurityBadge::Pauser),
+            4 => Ok(SecurityBadge::Allowlister),
+            5 => Ok(SecurityBadge::Burner),
+            6 => Ok(SecurityBadge::MintAndBurn),
+            _ => Err(Cep18Error::InvalidRole),
+        }
+    }
+}
+
+/// Reverts with `InsufficientRights` unless the caller holds at least one of the given roles.
 pub fn sec_check(allowed_badge_list: Vec<SecurityBadge>) {
-    let caller = get_immediate_caller_address()
-        .unwrap_or_revert()
-        .to_bytes()
-        .unwrap_or_revert();
-    if !allowed_badge_list.contains(
-        &dictionary_get::<SecurityBadge>(get_uref(SECURITY_BADGES), &base64::encode(caller))
-            .unwrap_or_revert()
-            .unwrap_or_revert_with(Cep18Error::InsufficientRights),
-    ) {
+    let caller = get_immediate_caller_address().unwrap_or_revert();
+    if !roles::read_roles(caller).intersects(&allowed_badge_list) {
         revert(Cep18Error::InsufficientRights)
     }
 }
 
+/// Replaces the roles of each account with the single role of its badge. `SecurityBadge::None`
+/// removes all roles.
 pub fn change_sec_badge(badge_map: &BTreeMap<Key, SecurityBadge>) {
-    let sec_uref = get_uref(SECURITY_BADGES);
     for (&user, &badge) in badge_map {
-        dictionary_put(
-            sec_uref,
-            &base64::encode(user.to_bytes().unwrap_or_revert()),
-            badge,
-        )
+        roles::write_roles(user, Roles::from(badge));
     }
 }
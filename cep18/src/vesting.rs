@@ -0,0 +1,143 @@
+//! Implementation of per-holder vesting schedules.
+//!
+//! A vesting schedule locks tokens of a holder that are released linearly over its duration,
+//! nothing being released before its cliff. Only the unlocked part of a balance can be spent.
+use core::convert::TryFrom;
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{Key, U256};
+
+use crate::{
+    constants::{ENABLE_VESTING, VESTING_SCHEDULES},
+    error::Cep18Error,
+    modalities::Vesting,
+    utils::{self, get_dictionary_uref, make_dictionary_item_key},
+};
+
+/// Longest time, in milliseconds from the current block time, by which a vesting schedule must
+/// end: about ten years.
+const MAX_VESTING_PERIOD: u64 = 10 * 365 * 24 * 60 * 60 * 1_000;
+
+/// Returns `true` if the `Vesting` modality was enabled at install.
+pub(crate) fn vesting_enabled() -> bool {
+    let enable_vesting = utils::read_optional_from::<u8>(ENABLE_VESTING).unwrap_or_default();
+    Vesting::try_from(enable_vesting).unwrap_or_revert() == Vesting::Enabled
+}
+
+/// Vesting schedule of a holder. The cliff and the duration are in milliseconds from the start.
+pub(crate) struct VestingSchedule {
+    pub start: u64,
+    pub cliff: u64,
+    pub duration: u64,
+    pub total: U256,
+}
+
+impl VestingSchedule {
+    /// Returns the amount vested at a given block time, rounded down.
+    fn vested_at(&self, blocktime: u64) -> U256 {
+        let elapsed = match blocktime.checked_sub(self.start) {
+            Some(elapsed) if elapsed >= self.cliff => elapsed,
+            _ => return U256::zero(),
+        };
+        if elapsed >= self.duration {
+            return self.total;
+        }
+        // Split the total to compute `total * elapsed / duration` without overflowing.
+        let (quotient, remainder) = div_rem_u64(self.total, self.duration);
+        let remainder_share =
+            u128::from(remainder) * u128::from(elapsed) / u128::from(self.duration);
+        quotient * U256::from(elapsed) + U256::from(remainder_share)
+    }
+}
+
+/// Divides a `U256` by a non-zero `u64`, returning the quotient and the remainder.
+///
+/// Dividing limb by limb with 128-bit arithmetic keeps the Wasm stack of transfers much shallower
+/// than the generic `U256` division.
+fn div_rem_u64(value: U256, divisor: u64) -> (U256, u64) {
+    let divisor = u128::from(divisor);
+    let mut quotient = [0u64; 4];
+    let mut remainder = 0u128;
+    for index in (0..4).rev() {
+        let dividend = remainder << 64 | u128::from(value.0[index]);
+        quotient[index] = (dividend / divisor) as u64;
+        remainder = dividend % divisor;
+    }
+    (U256(quotient), remainder as u64)
+}
+
+/// Reads the vesting schedule of a holder, if any.
+pub(crate) fn read_vesting_schedule(holder: Key) -> Option<VestingSchedule> {
+    let vesting_schedules_uref = match runtime::get_key(VESTING_SCHEDULES) {
+        Some(key) => key.into_uref().unwrap_or_revert(),
+        None => return None,
+    };
+    storage::dictionary_get::<((u64, u64, u64), U256)>(
+        vesting_schedules_uref,
+        &make_dictionary_item_key(holder),
+    )
+    .unwrap_or_revert()
+    .map(|((start, cliff, duration), total)| VestingSchedule {
+        start,
+        cliff,
+        duration,
+        total,
+    })
+}
+
+/// Creates the vesting schedule of a holder. A holder can only have one schedule at a time, and a
+/// new one can only replace a fully vested schedule. The schedule must end within
+/// `MAX_VESTING_PERIOD` of the current block time.
+///
+/// This function does not move the locked tokens, which the caller must grant to the holder.
+pub(crate) fn create_vesting_schedule(
+    holder: Key,
+    schedule: &VestingSchedule,
+) -> Result<(), Cep18Error> {
+    let blocktime: u64 = runtime::get_blocktime().into();
+    let latest_end = blocktime.saturating_add(MAX_VESTING_PERIOD);
+    if schedule.total.is_zero()
+        || schedule.cliff > schedule.duration
+        || !matches!(schedule.start.checked_add(schedule.duration), Some(end) if end <= latest_end)
+    {
+        return Err(Cep18Error::InvalidVestingSchedule);
+    }
+    if !read_locked_balance(holder).is_zero() {
+        return Err(Cep18Error::VestingScheduleExists);
+    }
+    storage::dictionary_put(
+        get_dictionary_uref(VESTING_SCHEDULES),
+        &make_dictionary_item_key(holder),
+        (
+            (schedule.start, schedule.cliff, schedule.duration),
+            schedule.total,
+        ),
+    );
+    Ok(())
+}
+
+/// Reads the amount vested so far by a holder, `0` if it has no vesting schedule.
+pub(crate) fn read_vested_amount(holder: Key) -> U256 {
+    read_vesting_schedule(holder)
+        .map(|schedule| schedule.vested_at(runtime::get_blocktime().into()))
+        .unwrap_or_default()
+}
+
+/// Reads the amount of tokens of a holder that are still locked.
+pub(crate) fn read_locked_balance(holder: Key) -> U256 {
+    read_vesting_schedule(holder)
+        .map(|schedule| schedule.total - schedule.vested_at(runtime::get_blocktime().into()))
+        .unwrap_or_default()
+}
+
+/// Checks that a new balance of a holder, after spending tokens, does not dip into its locked
+/// tokens.
+pub(crate) fn check_unlocked(holder: Key, new_balance: U256) -> Result<(), Cep18Error> {
+    if new_balance < read_locked_balance(holder) {
+        return Err(Cep18Error::InsufficientUnlockedBalance);
+    }
+    Ok(())
+}
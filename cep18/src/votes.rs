@@ -0,0 +1,134 @@
+//! Implementation of vote delegation and checkpointed voting power.
+//!
+//! Accounts only have voting power once they delegate their votes, which they can delegate to
+//! themselves. The votes of each delegate are checkpointed by block time whenever they change.
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{Key, URef, U256};
+
+use crate::{
+    balances, checkpoints,
+    constants::{DELEGATES, VOTE_CHECKPOINTS},
+    error::Cep18Error,
+    events::{self, DelegateChanged, DelegateVotesChanged, Event},
+    utils::{self, make_dictionary_item_key},
+};
+
+/// Reads the delegate of a specified account.
+///
+/// Accounts that never delegated, and all accounts of contracts without any delegation yet, read
+/// as `None`.
+pub(crate) fn read_delegate_from(account: Key) -> Option<Key> {
+    let delegates_uref = match runtime::get_key(DELEGATES) {
+        Some(key) => key.into_uref().unwrap_or_revert(),
+        None => return None,
+    };
+    storage::dictionary_get(delegates_uref, &make_dictionary_item_key(account)).unwrap_or_revert()
+}
+
+/// Reads the current votes of a specified account.
+pub(crate) fn read_votes_from(account: Key) -> U256 {
+    match runtime::get_key(VOTE_CHECKPOINTS) {
+        Some(key) => checkpoints::latest(
+            key.into_uref().unwrap_or_revert(),
+            &make_dictionary_item_key(account),
+        )
+        .map(|(_, votes)| votes)
+        .unwrap_or_default(),
+        None => U256::zero(),
+    }
+}
+
+/// Reads the votes of a specified account at a past block time.
+pub(crate) fn read_past_votes_from(account: Key, blocktime: u64) -> Result<U256, Cep18Error> {
+    if blocktime >= runtime::get_blocktime().into() {
+        return Err(Cep18Error::FutureLookup);
+    }
+    let votes = match runtime::get_key(VOTE_CHECKPOINTS) {
+        Some(key) => checkpoints::lower_lookup(
+            key.into_uref().unwrap_or_revert(),
+            &make_dictionary_item_key(account),
+            blocktime,
+        ),
+        None => None,
+    };
+    Ok(votes.unwrap_or_default())
+}
+
+/// Delegates the votes of the `delegator` to the `delegatee`, moving its voting power.
+///
+/// Contracts installed, or upgraded from a version, without delegation get their delegation state
+/// on the first delegation.
+pub(crate) fn delegate(delegator: Key, delegatee: Key) -> Result<(), Cep18Error> {
+    let delegates_uref = match runtime::get_key(DELEGATES) {
+        Some(key) => key.into_uref().unwrap_or_revert(),
+        None => {
+            storage::new_dictionary(VOTE_CHECKPOINTS).unwrap_or_revert();
+            storage::new_dictionary(DELEGATES).unwrap_or_revert()
+        }
+    };
+    let from_delegate = read_delegate_from(delegator);
+    storage::dictionary_put(
+        delegates_uref,
+        &make_dictionary_item_key(delegator),
+        delegatee,
+    );
+    events::record_event_dictionary(Event::DelegateChanged(DelegateChanged {
+        delegator,
+        from_delegate,
+        to_delegate: delegatee,
+    }));
+    let balance = balances::read_balance_from(balances::get_balances_uref(), delegator);
+    move_voting_power(from_delegate, Some(delegatee), balance)
+}
+
+/// Moves `amount` votes from the `source` delegate to the `destination` delegate, either of which
+/// can be `None` when the tokens are not delegated, minted or burned.
+///
+/// Nothing is read or written when neither side has delegated, so transfers between accounts
+/// that never delegated skip the vote bookkeeping.
+pub(crate) fn move_voting_power(
+    source: Option<Key>,
+    destination: Option<Key>,
+    amount: U256,
+) -> Result<(), Cep18Error> {
+    if source == destination || amount.is_zero() {
+        return Ok(());
+    }
+    let vote_checkpoints_uref = utils::get_uref(VOTE_CHECKPOINTS);
+    if let Some(source) = source {
+        write_votes(vote_checkpoints_uref, source, |votes| {
+            votes.checked_sub(amount)
+        })?;
+    }
+    if let Some(destination) = destination {
+        write_votes(vote_checkpoints_uref, destination, |votes| {
+            votes.checked_add(amount)
+        })?;
+    }
+    Ok(())
+}
+
+/// Checkpoints the votes of a delegate at the current block time.
+fn write_votes(
+    vote_checkpoints_uref: URef,
+    delegate: Key,
+    update: impl FnOnce(U256) -> Option<U256>,
+) -> Result<(), Cep18Error> {
+    let previous_votes = read_votes_from(delegate);
+    let new_votes = update(previous_votes).ok_or(Cep18Error::Overflow)?;
+    checkpoints::push(
+        vote_checkpoints_uref,
+        &make_dictionary_item_key(delegate),
+        runtime::get_blocktime().into(),
+        new_votes,
+    );
+    events::record_event_dictionary(Event::DelegateVotesChanged(DelegateVotesChanged {
+        delegate,
+        previous_votes,
+        new_votes,
+    }));
+    Ok(())
+}
@@ -39,6 +39,116 @@ export enum ERROR_CODES {
   InvalidBurnTarget = 60018,
   /// There is an AccessToken but no valid ContractPackageHash. Try to query the account for NamedKeys to find it.
   MissingPackageHashForUpgrade = 60019,
+  /// The contract is paused.
+  ContractPaused = 60020,
+  /// The pause mode is disabled.
+  PauseDisabled = 60021,
+  /// The flag to enable the pause mode is invalid.
+  InvalidEnablePauseFlag = 60022,
+  /// The list of accounts that can pause is invalid.
+  InvalidPauserList = 60023,
+  /// The sender or recipient account is frozen.
+  AccountFrozen = 60024,
+  /// The freeze mode is disabled.
+  FreezeDisabled = 60025,
+  /// The flag to enable the freeze mode is invalid.
+  InvalidEnableFreezeFlag = 60026,
+  /// The sender is not on the allowlist.
+  SenderNotAllowlisted = 60027,
+  /// The recipient is not on the allowlist.
+  RecipientNotAllowlisted = 60028,
+  /// The allowlist transfer restriction is disabled.
+  AllowlistDisabled = 60029,
+  /// An invalid transfer restriction was specified.
+  InvalidTransferRestriction = 60030,
+  /// The list of accounts that can manage the allowlist is invalid.
+  InvalidAllowlisterList = 60031,
+  /// The list of accounts to add to the allowlist is invalid.
+  InvalidAllowList = 60032,
+  /// The list of accounts to remove from the allowlist is invalid.
+  InvalidDisallowList = 60033,
+  /// The total supply would exceed the maximum supply.
+  ExceedsMaxSupply = 60034,
+  /// The maximum supply provided is invalid.
+  InvalidMaxSupply = 60035,
+  /// The allowance expiry provided is invalid.
+  InvalidAllowanceExpiry = 60036,
+  /// The permit deadline has passed.
+  PermitExpired = 60037,
+  /// The permit nonce does not match the owner's current nonce.
+  InvalidNonce = 60038,
+  /// The permit signature is invalid.
+  InvalidSignature = 60039,
+  /// An invalid batch transfer events mode was specified.
+  InvalidBatchTransferEvents = 60040,
+  /// The list of initial balances is invalid.
+  InvalidInitialBalances = 60041,
+  /// The list of accounts that can burn tokens from any account is invalid.
+  InvalidBurnerList = 60042,
+  /// The list of accounts that can both mint and burn tokens is invalid.
+  InvalidMintAndBurnList = 60043,
+  /// The role provided is invalid.
+  InvalidRole = 60044,
+  /// The change would remove the last Admin.
+  LastAdmin = 60045,
+  /// The account has no pending Admin nomination.
+  NoAdminNomination = 60046,
+  /// The account provided is invalid.
+  InvalidAccount = 60047,
+  /// The mint exceeds the remaining quota of the minter.
+  ExceedsMinterAllowance = 60048,
+  /// The mint exceeds the amount the minter can still mint in the current window.
+  ExceedsMintRateLimit = 60049,
+  /// The mint rate limit provided is invalid.
+  InvalidMintRateLimit = 60050,
+  /// The mint rate window provided is invalid.
+  InvalidMintRateWindow = 60051,
+  /// The transfer fee modality provided is invalid.
+  InvalidTransferFee = 60052,
+  /// The fee rate provided is invalid or exceeds the maximum fee rate.
+  InvalidFeeRate = 60053,
+  /// The maximum fee rate provided is invalid.
+  InvalidMaxFeeRate = 60054,
+  /// The treasury provided is invalid.
+  InvalidTreasury = 60055,
+  /// The fee exempt list provided is invalid.
+  InvalidFeeExemptList = 60056,
+  /// The fee non-exempt list provided is invalid.
+  InvalidFeeNonExemptList = 60057,
+  /// The transfer fee modality is disabled.
+  TransferFeeDisabled = 60058,
+  /// The snapshot id provided does not refer to a snapshot taken.
+  InvalidSnapshotId = 60059,
+  /// The block time provided has not elapsed yet.
+  FutureLookup = 60060,
+  /// The vesting schedule provided is invalid.
+  InvalidVestingSchedule = 60061,
+  /// The holder already has a vesting schedule that is not fully vested.
+  VestingScheduleExists = 60062,
+  /// The unlocked balance is too low, the rest being locked by a vesting schedule.
+  InsufficientUnlockedBalance = 60063,
+  /// The recipient is not a contract package.
+  InvalidReceiver = 60064,
+  /// The recipient contract rejected the transfer.
+  TransferRejected = 60065,
+  /// The recipient cannot receive tokens through safe transfers.
+  UnsafeRecipient = 60066,
+  /// The flash mint modality provided is invalid.
+  InvalidFlashMint = 60067,
+  /// The flash fee rate provided is invalid.
+  InvalidFlashFeeRate = 60068,
+  /// The flash mint modality is disabled.
+  FlashMintDisabled = 60069,
+  /// The amount exceeds the maximum flash loan.
+  ExceedsMaxFlashLoan = 60070,
+  /// The borrower contract rejected the flash loan.
+  FlashLoanRejected = 60071,
+  /// The borrower contract did not hold the amount and fee to repay at the end of the flash mint.
+  FlashLoanNotRepaid = 60072,
+  /// The vesting modality provided is invalid.
+  InvalidEnableVestingFlag = 60073,
+  /// The vesting modality is disabled.
+  VestingDisabled = 60074,
 }
 
 export class ContractError extends Error {
@@ -1,4 +1,16 @@
-import { CLKey, CLU256, CLValue } from 'casper-js-sdk';
+import {
+  CLBool,
+  CLKey,
+  CLList,
+  CLMap,
+  CLOption,
+  CLTuple2,
+  CLU8,
+  CLU32,
+  CLU64,
+  CLU256,
+  CLValue
+} from 'casper-js-sdk';
 
 export type Event<E extends Record<string, CLValue>> = {
   name: string;
@@ -24,6 +36,31 @@ export type CEP18Event = Event<
   | DecreaseAllowance
   | Transfer
   | TransferFrom
+  | ChangeSecurity
+  | Paused
+  | Unpaused
+  | Frozen
+  | Unfrozen
+  | ChangeAllowlist
+  | LowerMaxSupply
+  | BatchTransfer
+  | RoleGranted
+  | RoleRevoked
+  | AdminProposed
+  | AdminAccepted
+  | AdminProposalCancelled
+  | MinterAllowanceChanged
+  | MintRateLimitChanged
+  | FeeCharged
+  | FeeRateChanged
+  | TreasuryChanged
+  | ChangeFeeExemption
+  | Snapshot
+  | DelegateChanged
+  | DelegateVotesChanged
+  | VestingScheduleCreated
+  | TransferWithMemo
+  | FlashMint
 >;
 
 export type EventsMap = {
@@ -34,6 +71,31 @@ export type EventsMap = {
   DecreaseAllowance: Event<DecreaseAllowance>;
   Transfer: Event<Transfer>;
   TransferFrom: Event<TransferFrom>;
+  ChangeSecurity: Event<ChangeSecurity>;
+  Paused: Event<Paused>;
+  Unpaused: Event<Unpaused>;
+  Frozen: Event<Frozen>;
+  Unfrozen: Event<Unfrozen>;
+  ChangeAllowlist: Event<ChangeAllowlist>;
+  LowerMaxSupply: Event<LowerMaxSupply>;
+  BatchTransfer: Event<BatchTransfer>;
+  RoleGranted: Event<RoleGranted>;
+  RoleRevoked: Event<RoleRevoked>;
+  AdminProposed: Event<AdminProposed>;
+  AdminAccepted: Event<AdminAccepted>;
+  AdminProposalCancelled: Event<AdminProposalCancelled>;
+  MinterAllowanceChanged: Event<MinterAllowanceChanged>;
+  MintRateLimitChanged: Event<MintRateLimitChanged>;
+  FeeCharged: Event<FeeCharged>;
+  FeeRateChanged: Event<FeeRateChanged>;
+  TreasuryChanged: Event<TreasuryChanged>;
+  ChangeFeeExemption: Event<ChangeFeeExemption>;
+  Snapshot: Event<Snapshot>;
+  DelegateChanged: Event<DelegateChanged>;
+  DelegateVotesChanged: Event<DelegateVotesChanged>;
+  VestingScheduleCreated: Event<VestingScheduleCreated>;
+  TransferWithMemo: Event<TransferWithMemo>;
+  FlashMint: Event<FlashMint>;
 };
 
 export type Mint = {
@@ -44,12 +106,14 @@ export type Mint = {
 export type Burn = {
   owner: CLKey;
   amount: CLU256;
+  spender: CLOption<CLKey>;
 };
 
 export type SetAllowance = {
   owner: CLKey;
   spender: CLKey;
   allowance: CLU256;
+  expires_at: CLOption<CLU64>;
 };
 
 export type IncreaseAllowance = {
@@ -57,6 +121,7 @@ export type IncreaseAllowance = {
   spender: CLKey;
   allowance: CLU256;
   inc_by: CLU256;
+  expires_at: CLOption<CLU64>;
 };
 
 export type DecreaseAllowance = {
@@ -78,3 +143,141 @@ export type TransferFrom = {
   recipient: CLKey;
   amount: CLU256;
 };
+
+export type ChangeSecurity = {
+  admin: CLKey;
+  sec_change_map: CLMap<CLKey, CLU8>;
+};
+
+export type Paused = {
+  pauser: CLKey;
+};
+
+export type Unpaused = {
+  pauser: CLKey;
+};
+
+export type Frozen = {
+  admin: CLKey;
+  account: CLKey;
+};
+
+export type Unfrozen = {
+  admin: CLKey;
+  account: CLKey;
+};
+
+export type ChangeAllowlist = {
+  manager: CLKey;
+  allowlist_change_map: CLMap<CLKey, CLBool>;
+};
+
+export type LowerMaxSupply = {
+  admin: CLKey;
+  max_supply: CLU256;
+};
+
+export type BatchTransfer = {
+  sender: CLKey;
+  transfers: CLList<CLTuple2>;
+};
+
+export type RoleGranted = {
+  admin: CLKey;
+  account: CLKey;
+  role: CLU8;
+};
+
+export type RoleRevoked = {
+  admin: CLKey;
+  account: CLKey;
+  role: CLU8;
+};
+
+export type AdminProposed = {
+  proposer: CLKey;
+  nominee: CLKey;
+};
+
+export type AdminAccepted = {
+  proposer: CLKey;
+  nominee: CLKey;
+};
+
+export type AdminProposalCancelled = {
+  proposer: CLKey;
+  nominee: CLKey;
+};
+
+export type MinterAllowanceChanged = {
+  admin: CLKey;
+  minter: CLKey;
+  allowance: CLOption<CLU256>;
+};
+
+export type MintRateLimitChanged = {
+  admin: CLKey;
+  limit: CLU256;
+  window: CLU64;
+};
+
+export type FeeCharged = {
+  payer: CLKey;
+  treasury: CLKey;
+  fee: CLU256;
+};
+
+export type FeeRateChanged = {
+  admin: CLKey;
+  fee_rate: CLU32;
+};
+
+export type TreasuryChanged = {
+  admin: CLKey;
+  treasury: CLKey;
+};
+
+export type ChangeFeeExemption = {
+  admin: CLKey;
+  fee_exemption_change_map: CLMap<CLKey, CLBool>;
+};
+
+export type Snapshot = {
+  admin: CLKey;
+  snapshot_id: CLU64;
+};
+
+export type DelegateChanged = {
+  delegator: CLKey;
+  from_delegate: CLOption<CLKey>;
+  to_delegate: CLKey;
+};
+
+export type DelegateVotesChanged = {
+  delegate: CLKey;
+  previous_votes: CLU256;
+  new_votes: CLU256;
+};
+
+export type VestingScheduleCreated = {
+  admin: CLKey;
+  holder: CLKey;
+  start: CLU64;
+  cliff: CLU64;
+  duration: CLU64;
+  total: CLU256;
+};
+
+export type TransferWithMemo = {
+  sender: CLKey;
+  recipient: CLKey;
+  amount: CLU256;
+  memo: CLU64;
+};
+
+export type FlashMint = {
+  initiator: CLKey;
+  borrower: CLKey;
+  amount: CLU256;
+  fee: CLU256;
+};
@@ -0,0 +1,140 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ADMIN_COUNT_KEY, ARG_ACCOUNT, ARG_DECIMALS, ARG_NAME,
+        ARG_SYMBOL, ARG_TOTAL_SUPPLY, ENABLE_MINT_BURN, ERROR_INSUFFICIENT_RIGHTS,
+        ERROR_NO_ADMIN_NOMINATION, METHOD_ACCEPT_ADMIN, METHOD_CANCEL_ADMIN_PROPOSAL,
+        METHOD_PROPOSE_ADMIN, ROLE_ADMIN, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL,
+        TOKEN_TOTAL_SUPPLY,
+    },
+    installer_request_builders::{cep18_check_has_role, setup_with_args, TestContext},
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+#[test]
+fn should_make_nominee_admin_only_after_accepting() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+    });
+    let nominee = Key::Account(*ACCOUNT_1_ADDR);
+
+    let propose_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_PROPOSE_ADMIN,
+        runtime_args! {
+            ARG_ACCOUNT => nominee,
+        },
+    )
+    .build();
+    builder.exec(propose_request).expect_success().commit();
+
+    assert!(!cep18_check_has_role(&mut builder, nominee, ROLE_ADMIN));
+
+    let accept_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_ACCEPT_ADMIN,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(accept_request).expect_success().commit();
+
+    assert!(cep18_check_has_role(&mut builder, nominee, ROLE_ADMIN));
+    let admin_count: u32 = builder.get_value(cep18_token, ADMIN_COUNT_KEY);
+    assert_eq!(admin_count, 2);
+
+    let accept_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_ACCEPT_ADMIN,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(accept_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_NO_ADMIN_NOMINATION),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_only_let_proposer_cancel_nomination() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+    });
+    let nominee = Key::Account(*ACCOUNT_1_ADDR);
+
+    let propose_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_PROPOSE_ADMIN,
+        runtime_args! {
+            ARG_ACCOUNT => nominee,
+        },
+    )
+    .build();
+    builder.exec(propose_request).expect_success().commit();
+
+    let cancel_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_2_ADDR,
+        cep18_token,
+        METHOD_CANCEL_ADMIN_PROPOSAL,
+        runtime_args! {
+            ARG_ACCOUNT => nominee,
+        },
+    )
+    .build();
+    builder.exec(cancel_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_RIGHTS),
+        "{:?}",
+        error
+    );
+
+    let cancel_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_CANCEL_ADMIN_PROPOSAL,
+        runtime_args! {
+            ARG_ACCOUNT => nominee,
+        },
+    )
+    .build();
+    builder.exec(cancel_request).expect_success().commit();
+
+    let accept_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_ACCEPT_ADMIN,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(accept_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_NO_ADMIN_NOMINATION),
+        "{:?}",
+        error
+    );
+    assert!(!cep18_check_has_role(&mut builder, nominee, ROLE_ADMIN));
+}
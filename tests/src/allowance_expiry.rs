@@ -0,0 +1,280 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ALLOWANCE_AMOUNT_1, ARG_AMOUNT, ARG_EXPIRES_AT, ARG_OWNER, ARG_RECIPIENT,
+        ARG_SPENDER, ARG_TOKEN_CONTRACT, CHECK_ALLOWANCE_WITH_EXPIRY_OF_ENTRYPOINT,
+        ERROR_INSUFFICIENT_ALLOWANCE, INCREASE_ALLOWANCE, METHOD_APPROVE, METHOD_TRANSFER_FROM,
+    },
+    installer_request_builders::{cep18_check_allowance_of, get_test_result, setup, TestContext},
+};
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+const EXPIRES_AT: u64 = 1_000;
+
+#[test]
+fn should_honour_allowance_expiry_in_transfer_from() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let spender = Key::Account(*ACCOUNT_1_ADDR);
+    let amount = U256::one();
+
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_APPROVE,
+        runtime_args! {
+            ARG_SPENDER => spender,
+            ARG_AMOUNT => U256::from(ALLOWANCE_AMOUNT_1),
+            ARG_EXPIRES_AT => EXPIRES_AT,
+        },
+    )
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+
+    let transfer_from_args = runtime_args! {
+        ARG_OWNER => owner,
+        ARG_RECIPIENT => spender,
+        ARG_AMOUNT => amount,
+    };
+    let transfer_from_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_TRANSFER_FROM,
+        transfer_from_args.clone(),
+    )
+    .with_block_time(EXPIRES_AT - 1)
+    .build();
+    builder
+        .exec(transfer_from_request)
+        .expect_success()
+        .commit();
+
+    let transfer_from_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_TRANSFER_FROM,
+        transfer_from_args,
+    )
+    .with_block_time(EXPIRES_AT)
+    .build();
+    builder.exec(transfer_from_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_ALLOWANCE),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_return_allowance_with_expiry() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_token,
+            cep18_test_contract_package,
+            ..
+        },
+    ) = setup();
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let spender = Key::Hash([42; 32]);
+    let allowance_amount = U256::from(ALLOWANCE_AMOUNT_1);
+
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_APPROVE,
+        runtime_args! {
+            ARG_SPENDER => spender,
+            ARG_AMOUNT => allowance_amount,
+            ARG_EXPIRES_AT => EXPIRES_AT,
+        },
+    )
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+
+    let check_allowance_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(cep18_token),
+        ARG_OWNER => owner,
+        ARG_SPENDER => spender,
+    };
+    let check_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_ALLOWANCE_WITH_EXPIRY_OF_ENTRYPOINT,
+        check_allowance_args.clone(),
+    )
+    .with_block_time(EXPIRES_AT - 1)
+    .build();
+    builder.exec(check_request).expect_success().commit();
+    let result: (U256, Option<u64>) = get_test_result(&mut builder, cep18_test_contract_package);
+    assert_eq!(result, (allowance_amount, Some(EXPIRES_AT)));
+
+    let check_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_ALLOWANCE_WITH_EXPIRY_OF_ENTRYPOINT,
+        check_allowance_args,
+    )
+    .with_block_time(EXPIRES_AT)
+    .build();
+    builder.exec(check_request).expect_success().commit();
+    let result: (U256, Option<u64>) = get_test_result(&mut builder, cep18_test_contract_package);
+    assert_eq!(result, (U256::zero(), Some(EXPIRES_AT)));
+}
+
+#[test]
+fn should_reset_expiry_on_approve_and_increase_allowance() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let spender = Key::Hash([42; 32]);
+    let allowance_amount = U256::from(ALLOWANCE_AMOUNT_1);
+
+    // Expires at the default block time, so reads as zero straight away.
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_APPROVE,
+        runtime_args! {
+            ARG_SPENDER => spender,
+            ARG_AMOUNT => allowance_amount,
+            ARG_EXPIRES_AT => 0u64,
+        },
+    )
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+    assert_eq!(
+        cep18_check_allowance_of(&mut builder, owner, spender),
+        U256::zero()
+    );
+
+    // Omitting `expires_at` keeps the expiry, so the increased allowance still reads as zero.
+    let increase_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        INCREASE_ALLOWANCE,
+        runtime_args! {
+            ARG_SPENDER => spender,
+            ARG_AMOUNT => allowance_amount,
+        },
+    )
+    .build();
+    builder.exec(increase_request).expect_success().commit();
+    assert_eq!(
+        cep18_check_allowance_of(&mut builder, owner, spender),
+        U256::zero()
+    );
+
+    // Passing `expires_at` replaces the expiry of the whole allowance.
+    let increase_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        INCREASE_ALLOWANCE,
+        runtime_args! {
+            ARG_SPENDER => spender,
+            ARG_AMOUNT => allowance_amount,
+            ARG_EXPIRES_AT => EXPIRES_AT,
+        },
+    )
+    .build();
+    builder.exec(increase_request).expect_success().commit();
+    assert_eq!(
+        cep18_check_allowance_of(&mut builder, owner, spender),
+        allowance_amount
+    );
+
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_APPROVE,
+        runtime_args! {
+            ARG_SPENDER => spender,
+            ARG_AMOUNT => allowance_amount,
+            ARG_EXPIRES_AT => 0u64,
+        },
+    )
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+    assert_eq!(
+        cep18_check_allowance_of(&mut builder, owner, spender),
+        U256::zero()
+    );
+}
+
+#[test]
+fn should_keep_expiry_on_increase_allowance() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_token,
+            cep18_test_contract_package,
+            ..
+        },
+    ) = setup();
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let spender = Key::Hash([42; 32]);
+    let allowance_amount = U256::from(ALLOWANCE_AMOUNT_1);
+
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_APPROVE,
+        runtime_args! {
+            ARG_SPENDER => spender,
+            ARG_AMOUNT => allowance_amount,
+            ARG_EXPIRES_AT => EXPIRES_AT,
+        },
+    )
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+
+    let increase_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        INCREASE_ALLOWANCE,
+        runtime_args! {
+            ARG_SPENDER => spender,
+            ARG_AMOUNT => allowance_amount,
+        },
+    )
+    .build();
+    builder.exec(increase_request).expect_success().commit();
+
+    let check_allowance_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(cep18_token),
+        ARG_OWNER => owner,
+        ARG_SPENDER => spender,
+    };
+    let check_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_ALLOWANCE_WITH_EXPIRY_OF_ENTRYPOINT,
+        check_allowance_args.clone(),
+    )
+    .with_block_time(EXPIRES_AT - 1)
+    .build();
+    builder.exec(check_request).expect_success().commit();
+    let result: (U256, Option<u64>) = get_test_result(&mut builder, cep18_test_contract_package);
+    assert_eq!(result, (allowance_amount * 2, Some(EXPIRES_AT)));
+
+    let check_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_ALLOWANCE_WITH_EXPIRY_OF_ENTRYPOINT,
+        check_allowance_args,
+    )
+    .with_block_time(EXPIRES_AT)
+    .build();
+    builder.exec(check_request).expect_success().commit();
+    let result: (U256, Option<u64>) = get_test_result(&mut builder, cep18_test_contract_package);
+    assert_eq!(result, (U256::zero(), Some(EXPIRES_AT)));
+}
@@ -0,0 +1,238 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ALLOWLISTER_LIST, ALLOW_LIST, ARG_AMOUNT, ARG_DECIMALS,
+        ARG_NAME, ARG_OWNER, ARG_SYMBOL, ARG_TOTAL_SUPPLY, DISALLOW_LIST, ENABLE_MINT_BURN,
+        ERROR_ALLOWLIST_DISABLED, ERROR_INSUFFICIENT_RIGHTS, ERROR_RECIPIENT_NOT_ALLOWLISTED,
+        ERROR_SENDER_NOT_ALLOWLISTED, METHOD_CHANGE_ALLOWLIST, METHOD_MINT, TOKEN_DECIMALS,
+        TOKEN_NAME, TOKEN_OWNER_ADDRESS_1, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY, TRANSFER_AMOUNT_1,
+        TRANSFER_RESTRICTION,
+    },
+    installer_request_builders::{
+        cep18_check_balance_of, make_cep18_transfer_request, setup_with_args, TestContext,
+    },
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+#[test]
+fn should_only_transfer_between_allowlisted_accounts() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        TRANSFER_RESTRICTION => 1u8,
+    });
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_1_ADDR);
+
+    let transfer_request = make_cep18_transfer_request(
+        owner,
+        &cep18_token,
+        recipient,
+        U256::from(TRANSFER_AMOUNT_1),
+    );
+    builder.exec(transfer_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_SENDER_NOT_ALLOWLISTED),
+        "{:?}",
+        error
+    );
+
+    let change_allowlist_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_CHANGE_ALLOWLIST,
+        runtime_args! {
+            ALLOW_LIST => vec![owner],
+        },
+    )
+    .build();
+    builder
+        .exec(change_allowlist_request)
+        .expect_success()
+        .commit();
+
+    let transfer_request = make_cep18_transfer_request(
+        owner,
+        &cep18_token,
+        recipient,
+        U256::from(TRANSFER_AMOUNT_1),
+    );
+    builder.exec(transfer_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_RECIPIENT_NOT_ALLOWLISTED),
+        "{:?}",
+        error
+    );
+
+    let change_allowlist_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_CHANGE_ALLOWLIST,
+        runtime_args! {
+            ALLOW_LIST => vec![recipient],
+        },
+    )
+    .build();
+    builder
+        .exec(change_allowlist_request)
+        .expect_success()
+        .commit();
+
+    let transfer_request = make_cep18_transfer_request(
+        owner,
+        &cep18_token,
+        recipient,
+        U256::from(TRANSFER_AMOUNT_1),
+    );
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, recipient),
+        U256::from(TRANSFER_AMOUNT_1)
+    );
+
+    // Disallow takes precedence over allow.
+    let change_allowlist_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_CHANGE_ALLOWLIST,
+        runtime_args! {
+            ALLOW_LIST => vec![recipient],
+            DISALLOW_LIST => vec![recipient],
+        },
+    )
+    .build();
+    builder
+        .exec(change_allowlist_request)
+        .expect_success()
+        .commit();
+
+    let transfer_request = make_cep18_transfer_request(
+        owner,
+        &cep18_token,
+        recipient,
+        U256::from(TRANSFER_AMOUNT_1),
+    );
+    builder.exec(transfer_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_RECIPIENT_NOT_ALLOWLISTED),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_mint_to_account_outside_allowlist() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        TRANSFER_RESTRICTION => 1u8,
+    });
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => U256::one(),
+        },
+    )
+    .build();
+    builder.exec(mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_RECIPIENT_NOT_ALLOWLISTED),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_only_allow_allowlisters_and_admins_to_change_allowlist() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        TRANSFER_RESTRICTION => 1u8,
+        ALLOWLISTER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+    });
+
+    let change_allowlist_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_2_ADDR,
+        cep18_token,
+        METHOD_CHANGE_ALLOWLIST,
+        runtime_args! {
+            ALLOW_LIST => vec![Key::Account(*ACCOUNT_2_ADDR)],
+        },
+    )
+    .build();
+    builder.exec(change_allowlist_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_RIGHTS),
+        "{:?}",
+        error
+    );
+
+    let change_allowlist_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_CHANGE_ALLOWLIST,
+        runtime_args! {
+            ALLOW_LIST => vec![Key::Account(*ACCOUNT_2_ADDR)],
+        },
+    )
+    .build();
+    builder
+        .exec(change_allowlist_request)
+        .expect_success()
+        .commit();
+}
+
+#[test]
+fn should_not_change_allowlist_with_restriction_disabled() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+    });
+
+    let change_allowlist_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_CHANGE_ALLOWLIST,
+        runtime_args! {
+            ALLOW_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+        },
+    )
+    .build();
+    builder.exec(change_allowlist_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_ALLOWLIST_DISABLED),
+        "{:?}",
+        error
+    );
+}
@@ -0,0 +1,154 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_DECIMALS, ARG_INITIAL_BALANCES, ARG_MAX_SUPPLY,
+        ARG_MINTS, ARG_NAME, ARG_SYMBOL, ARG_TOTAL_SUPPLY, ENABLE_MINT_BURN,
+        ERROR_EXCEEDS_MAX_SUPPLY, ERROR_INSUFFICIENT_RIGHTS, METHOD_BATCH_MINT, TOKEN_DECIMALS,
+        TOKEN_NAME, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY, TRANSFER_AMOUNT_1, TRANSFER_AMOUNT_2,
+    },
+    installer_request_builders::{
+        cep18_check_balance_of, cep18_check_total_supply, setup_with_args, TestContext,
+    },
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+#[test]
+fn should_batch_mint() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+    });
+    let owner_1 = Key::Account(*ACCOUNT_1_ADDR);
+    let owner_2 = Key::Hash([42; 32]);
+    let mints = vec![
+        (owner_1, U256::from(TRANSFER_AMOUNT_1)),
+        (owner_2, U256::from(TRANSFER_AMOUNT_2)),
+    ];
+
+    let batch_mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_BATCH_MINT,
+        runtime_args! {
+            ARG_MINTS => mints,
+        },
+    )
+    .build();
+    builder.exec(batch_mint_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, owner_1),
+        U256::from(TRANSFER_AMOUNT_1)
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, owner_2),
+        U256::from(TRANSFER_AMOUNT_2)
+    );
+    assert_eq!(
+        cep18_check_total_supply(&mut builder, &cep18_token),
+        U256::from(TOKEN_TOTAL_SUPPLY + TRANSFER_AMOUNT_1 + TRANSFER_AMOUNT_2)
+    );
+}
+
+#[test]
+fn should_not_batch_mint_without_rights_or_above_max_supply() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ARG_MAX_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY + TRANSFER_AMOUNT_1),
+        ENABLE_MINT_BURN => true,
+    });
+    let owner_1 = Key::Account(*ACCOUNT_1_ADDR);
+    let mints = vec![
+        (owner_1, U256::from(TRANSFER_AMOUNT_1)),
+        (Key::Hash([42; 32]), U256::one()),
+    ];
+
+    let batch_mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_BATCH_MINT,
+        runtime_args! {
+            ARG_MINTS => mints.clone(),
+        },
+    )
+    .build();
+    builder.exec(batch_mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_RIGHTS),
+        "{:?}",
+        error
+    );
+
+    let batch_mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_BATCH_MINT,
+        runtime_args! {
+            ARG_MINTS => mints,
+        },
+    )
+    .build();
+    builder.exec(batch_mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_EXCEEDS_MAX_SUPPLY),
+        "{:?}",
+        error
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, owner_1),
+        U256::zero()
+    );
+}
+
+#[test]
+fn should_credit_initial_balances_at_install() {
+    let owner_1 = Key::Account(*ACCOUNT_1_ADDR);
+    let owner_2 = Key::Account(*ACCOUNT_2_ADDR);
+
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ARG_INITIAL_BALANCES => vec![
+            (owner_1, U256::from(TRANSFER_AMOUNT_1)),
+            (owner_2, U256::from(TRANSFER_AMOUNT_2)),
+        ],
+    });
+
+    assert_eq!(
+        cep18_check_balance_of(
+            &mut builder,
+            &cep18_token,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR)
+        ),
+        U256::from(TOKEN_TOTAL_SUPPLY)
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, owner_1),
+        U256::from(TRANSFER_AMOUNT_1)
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, owner_2),
+        U256::from(TRANSFER_AMOUNT_2)
+    );
+    assert_eq!(
+        cep18_check_total_supply(&mut builder, &cep18_token),
+        U256::from(TOKEN_TOTAL_SUPPLY + TRANSFER_AMOUNT_1 + TRANSFER_AMOUNT_2)
+    );
+}
@@ -0,0 +1,137 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_DECIMALS, ARG_NAME, ARG_SYMBOL, ARG_TOTAL_SUPPLY,
+        ARG_TRANSFERS, BATCH_TRANSFER_EVENTS, ERROR_INSUFFICIENT_BALANCE, EVENTS_LENGTH_KEY,
+        EVENTS_MODE, METHOD_BATCH_TRANSFER, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL,
+        TOKEN_TOTAL_SUPPLY, TRANSFER_AMOUNT_1, TRANSFER_AMOUNT_2,
+    },
+    installer_request_builders::{cep18_check_balance_of, setup, setup_with_args, TestContext},
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+#[test]
+fn should_batch_transfer() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient_1 = Key::Account(*ACCOUNT_1_ADDR);
+    let recipient_2 = Key::Account(*ACCOUNT_2_ADDR);
+    let recipient_3 = Key::Hash([42; 32]);
+    let transfers = vec![
+        (recipient_1, U256::from(TRANSFER_AMOUNT_1)),
+        (recipient_2, U256::from(TRANSFER_AMOUNT_2)),
+        (recipient_3, U256::from(TRANSFER_AMOUNT_1)),
+        (recipient_1, U256::from(TRANSFER_AMOUNT_2)),
+    ];
+
+    let batch_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_BATCH_TRANSFER,
+        runtime_args! {
+            ARG_TRANSFERS => transfers,
+        },
+    )
+    .build();
+    builder
+        .exec(batch_transfer_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, recipient_1),
+        U256::from(TRANSFER_AMOUNT_1 + TRANSFER_AMOUNT_2)
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, recipient_2),
+        U256::from(TRANSFER_AMOUNT_2)
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, recipient_3),
+        U256::from(TRANSFER_AMOUNT_1)
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, sender),
+        U256::from(TOKEN_TOTAL_SUPPLY - 2 * TRANSFER_AMOUNT_1 - 2 * TRANSFER_AMOUNT_2)
+    );
+}
+
+#[test]
+fn should_revert_whole_batch_transfer_on_failure() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient_1 = Key::Account(*ACCOUNT_1_ADDR);
+    let recipient_2 = Key::Account(*ACCOUNT_2_ADDR);
+    let transfers = vec![
+        (recipient_1, U256::from(TRANSFER_AMOUNT_1)),
+        (recipient_2, U256::from(TOKEN_TOTAL_SUPPLY)),
+    ];
+
+    let batch_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_BATCH_TRANSFER,
+        runtime_args! {
+            ARG_TRANSFERS => transfers,
+        },
+    )
+    .build();
+    builder.exec(batch_transfer_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_BALANCE),
+        "{:?}",
+        error
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, recipient_1),
+        U256::zero()
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, sender),
+        U256::from(TOKEN_TOTAL_SUPPLY)
+    );
+}
+
+#[test]
+fn should_emit_one_event_per_transfer_or_per_batch() {
+    let transfers = vec![
+        (Key::Account(*ACCOUNT_1_ADDR), U256::from(TRANSFER_AMOUNT_1)),
+        (Key::Account(*ACCOUNT_2_ADDR), U256::from(TRANSFER_AMOUNT_2)),
+    ];
+
+    for (batch_transfer_events, expected_events) in [(0u8, 2u32), (1u8, 1u32)] {
+        let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+            ARG_NAME => TOKEN_NAME,
+            ARG_SYMBOL => TOKEN_SYMBOL,
+            ARG_DECIMALS => TOKEN_DECIMALS,
+            ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+            EVENTS_MODE => 1u8,
+            BATCH_TRANSFER_EVENTS => batch_transfer_events,
+        });
+        let events_before: u32 = builder.get_value(cep18_token, EVENTS_LENGTH_KEY);
+
+        let batch_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            cep18_token,
+            METHOD_BATCH_TRANSFER,
+            runtime_args! {
+                ARG_TRANSFERS => transfers.clone(),
+            },
+        )
+        .build();
+        builder
+            .exec(batch_transfer_request)
+            .expect_success()
+            .commit();
+
+        let events_after: u32 = builder.get_value(cep18_token, EVENTS_LENGTH_KEY);
+        assert_eq!(events_after - events_before, expected_events);
+    }
+}
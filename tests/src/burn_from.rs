@@ -0,0 +1,154 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ALLOWANCE_AMOUNT_1, ARG_ACCOUNT, ARG_AMOUNT, ARG_DECIMALS, ARG_NAME,
+        ARG_OWNER, ARG_SPENDER, ARG_SYMBOL, ARG_TOTAL_SUPPLY, ENABLE_FREEZE, ENABLE_MINT_BURN,
+        ERROR_ACCOUNT_FROZEN, ERROR_INSUFFICIENT_ALLOWANCE, METHOD_APPROVE, METHOD_BURN_FROM,
+        METHOD_FREEZE_ACCOUNT, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+    },
+    installer_request_builders::{
+        cep18_check_allowance_of, cep18_check_balance_of, cep18_check_total_supply,
+        setup_with_args, TestContext,
+    },
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+#[test]
+fn should_burn_from_within_allowance() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+    });
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let spender = Key::Account(*ACCOUNT_1_ADDR);
+    let allowance_amount = U256::from(ALLOWANCE_AMOUNT_1);
+    let burn_amount = allowance_amount / 2;
+
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_APPROVE,
+        runtime_args! {
+            ARG_SPENDER => spender,
+            ARG_AMOUNT => allowance_amount,
+        },
+    )
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+
+    let burn_from_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_BURN_FROM,
+        runtime_args! {
+            ARG_OWNER => owner,
+            ARG_AMOUNT => burn_amount,
+        },
+    )
+    .build();
+    builder.exec(burn_from_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, owner),
+        U256::from(TOKEN_TOTAL_SUPPLY) - burn_amount
+    );
+    assert_eq!(
+        cep18_check_total_supply(&mut builder, &cep18_token),
+        U256::from(TOKEN_TOTAL_SUPPLY) - burn_amount
+    );
+    assert_eq!(
+        cep18_check_allowance_of(&mut builder, owner, spender),
+        allowance_amount - burn_amount
+    );
+
+    let burn_from_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_BURN_FROM,
+        runtime_args! {
+            ARG_OWNER => owner,
+            ARG_AMOUNT => allowance_amount,
+        },
+    )
+    .build();
+    builder.exec(burn_from_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_ALLOWANCE),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_burn_from_with_frozen_spender() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        ENABLE_FREEZE => 1u8,
+    });
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let spender = Key::Account(*ACCOUNT_1_ADDR);
+
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_APPROVE,
+        runtime_args! {
+            ARG_SPENDER => spender,
+            ARG_AMOUNT => U256::from(ALLOWANCE_AMOUNT_1),
+        },
+    )
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+
+    let freeze_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_FREEZE_ACCOUNT,
+        runtime_args! {
+            ARG_ACCOUNT => spender,
+        },
+    )
+    .build();
+    builder.exec(freeze_request).expect_success().commit();
+
+    // Zero amounts must revert as well.
+    for amount in [U256::from(ALLOWANCE_AMOUNT_1), U256::zero()] {
+        let burn_from_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *ACCOUNT_1_ADDR,
+            cep18_token,
+            METHOD_BURN_FROM,
+            runtime_args! {
+                ARG_OWNER => owner,
+                ARG_AMOUNT => amount,
+            },
+        )
+        .build();
+        builder.exec(burn_from_request).commit();
+
+        let error = builder.get_error().expect("should have error");
+        assert!(
+            matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_ACCOUNT_FROZEN),
+            "{:?}",
+            error
+        );
+    }
+
+    assert_eq!(
+        cep18_check_total_supply(&mut builder, &cep18_token),
+        U256::from(TOKEN_TOTAL_SUPPLY)
+    );
+}
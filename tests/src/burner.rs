@@ -0,0 +1,202 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_AMOUNT, ARG_DECIMALS, ARG_NAME, ARG_OWNER, ARG_SYMBOL,
+        ARG_TOTAL_SUPPLY, BURNER_LIST, CHANGE_SECURITY, ENABLE_MINT_BURN,
+        ERROR_INSUFFICIENT_RIGHTS, ERROR_INVALID_BURN_TARGET, METHOD_BURN, METHOD_MINT,
+        MINT_AND_BURN_LIST, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+    },
+    installer_request_builders::{
+        cep18_check_balance_of, cep18_check_total_supply, make_cep18_transfer_request,
+        setup_with_args, TestContext,
+    },
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+#[test]
+fn should_let_burner_burn_from_any_account() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        BURNER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+    });
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let burn_amount = U256::from(100);
+
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_BURN,
+        runtime_args! {
+            ARG_OWNER => owner,
+            ARG_AMOUNT => burn_amount,
+        },
+    )
+    .build();
+    builder.exec(burn_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, owner),
+        U256::from(TOKEN_TOTAL_SUPPLY) - burn_amount
+    );
+    assert_eq!(
+        cep18_check_total_supply(&mut builder, &cep18_token),
+        U256::from(TOKEN_TOTAL_SUPPLY) - burn_amount
+    );
+
+    // burners cannot mint
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => owner,
+            ARG_AMOUNT => burn_amount,
+        },
+    )
+    .build();
+    builder.exec(mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_RIGHTS),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_only_burn_from_other_accounts_with_burn_rights() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+    });
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let amount = U256::from(100);
+
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_2_ADDR,
+        cep18_token,
+        METHOD_BURN,
+        runtime_args! {
+            ARG_OWNER => owner,
+            ARG_AMOUNT => amount,
+        },
+    )
+    .build();
+    builder.exec(burn_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INVALID_BURN_TARGET),
+        "{:?}",
+        error
+    );
+
+    let change_security_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        CHANGE_SECURITY,
+        runtime_args! {
+            MINT_AND_BURN_LIST => vec![Key::Account(*ACCOUNT_2_ADDR)],
+        },
+    )
+    .build();
+    builder
+        .exec(change_security_request)
+        .expect_success()
+        .commit();
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_2_ADDR,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_AMOUNT => amount,
+        },
+    )
+    .build();
+    builder.exec(mint_request).expect_success().commit();
+
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_2_ADDR,
+        cep18_token,
+        METHOD_BURN,
+        runtime_args! {
+            ARG_OWNER => owner,
+            ARG_AMOUNT => amount,
+        },
+    )
+    .build();
+    builder.exec(burn_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, owner),
+        U256::from(TOKEN_TOTAL_SUPPLY) - amount
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_1_ADDR)),
+        amount
+    );
+    assert_eq!(
+        cep18_check_total_supply(&mut builder, &cep18_token),
+        U256::from(TOKEN_TOTAL_SUPPLY)
+    );
+}
+
+#[test]
+fn should_not_let_admin_without_burn_rights_burn_from_other_accounts() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+    });
+    let holder = Key::Account(*ACCOUNT_1_ADDR);
+    let amount = U256::from(100);
+
+    let transfer_request = make_cep18_transfer_request(
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        &cep18_token,
+        holder,
+        amount,
+    );
+    builder.exec(transfer_request).expect_success().commit();
+
+    // The installer is an Admin without the Burner or MintAndBurn role.
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_BURN,
+        runtime_args! {
+            ARG_OWNER => holder,
+            ARG_AMOUNT => amount,
+        },
+    )
+    .build();
+    builder.exec(burn_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INVALID_BURN_TARGET),
+        "{:?}",
+        error
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, holder),
+        amount
+    );
+}
@@ -0,0 +1,205 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{bytesrepr::Bytes, runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ARG_AMOUNT, ARG_BORROWER, ARG_DATA, ARG_DECIMALS, ARG_ENABLE_FLASH_MINT,
+        ARG_FLASH_FEE_RATE, ARG_MAX_SUPPLY, ARG_NAME, ARG_RECIPIENT, ARG_SYMBOL, ARG_TOTAL_SUPPLY,
+        ERROR_EXCEEDS_MAX_FLASH_LOAN, ERROR_FLASH_LOAN_NOT_REPAID, ERROR_FLASH_LOAN_REJECTED,
+        ERROR_FLASH_MINT_DISABLED, ERROR_INVALID_RECEIVER, METHOD_FLASH_MINT, METHOD_TRANSFER,
+        TOKEN_DECIMALS, TOKEN_NAME, TOKEN_OWNER_ADDRESS_1, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+    },
+    installer_request_builders::{
+        cep18_check_balance_of, cep18_check_max_flash_loan, cep18_check_total_supply,
+        get_test_result, setup, setup_with_args, TestContext,
+    },
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+const MAX_FLASH_LOAN: u64 = 1_000_000;
+/// Flash fee rate of 1%, in basis points.
+const FLASH_FEE_RATE: u32 = 100;
+const FLASH_LOAN_AMOUNT: u64 = 500_000;
+const FLASH_FEE: u64 = 5_000;
+
+fn setup_with_flash_mint() -> (InMemoryWasmTestBuilder, TestContext) {
+    setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ARG_MAX_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY + MAX_FLASH_LOAN),
+        ARG_ENABLE_FLASH_MINT => 1u8,
+        ARG_FLASH_FEE_RATE => FLASH_FEE_RATE,
+    })
+}
+
+#[test]
+fn should_flash_mint_and_burn_repayment() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_token,
+            cep18_test_contract_package,
+        },
+    ) = setup_with_flash_mint();
+    let initiator = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let borrower = Key::from(cep18_test_contract_package);
+
+    assert_eq!(
+        cep18_check_max_flash_loan(&mut builder, &cep18_token),
+        U256::from(MAX_FLASH_LOAN)
+    );
+
+    let fee_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENT => borrower,
+            ARG_AMOUNT => U256::from(FLASH_FEE),
+        },
+    )
+    .build();
+    builder.exec(fee_transfer_request).expect_success().commit();
+
+    let flash_mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_FLASH_MINT,
+        runtime_args! {
+            ARG_BORROWER => borrower,
+            ARG_AMOUNT => U256::from(FLASH_LOAN_AMOUNT),
+            ARG_DATA => Bytes::new(),
+        },
+    )
+    .build();
+    builder.exec(flash_mint_request).expect_success().commit();
+
+    let loan: (Key, U256, U256) = get_test_result(&mut builder, cep18_test_contract_package);
+    assert_eq!(
+        loan,
+        (
+            initiator,
+            U256::from(FLASH_FEE),
+            U256::from(FLASH_LOAN_AMOUNT + FLASH_FEE)
+        )
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, borrower),
+        U256::zero()
+    );
+    assert_eq!(
+        cep18_check_total_supply(&mut builder, &cep18_token),
+        U256::from(TOKEN_TOTAL_SUPPLY - FLASH_FEE)
+    );
+    assert_eq!(
+        cep18_check_max_flash_loan(&mut builder, &cep18_token),
+        U256::from(MAX_FLASH_LOAN + FLASH_FEE)
+    );
+}
+
+#[test]
+fn should_revert_flash_mint_unless_repaid() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_token,
+            cep18_test_contract_package,
+        },
+    ) = setup_with_flash_mint();
+    let borrower = Key::from(cep18_test_contract_package);
+
+    let expected_errors = [
+        (
+            borrower,
+            MAX_FLASH_LOAN + 1,
+            Vec::new(),
+            ERROR_EXCEEDS_MAX_FLASH_LOAN,
+        ),
+        (
+            borrower,
+            FLASH_LOAN_AMOUNT,
+            b"reject".to_vec(),
+            ERROR_FLASH_LOAN_REJECTED,
+        ),
+        (
+            borrower,
+            FLASH_LOAN_AMOUNT,
+            Vec::new(),
+            ERROR_FLASH_LOAN_NOT_REPAID,
+        ),
+        (
+            TOKEN_OWNER_ADDRESS_1,
+            FLASH_LOAN_AMOUNT,
+            Vec::new(),
+            ERROR_INVALID_RECEIVER,
+        ),
+    ];
+    for (borrower, amount, data, expected_error) in expected_errors {
+        let flash_mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            cep18_token,
+            METHOD_FLASH_MINT,
+            runtime_args! {
+                ARG_BORROWER => borrower,
+                ARG_AMOUNT => U256::from(amount),
+                ARG_DATA => Bytes::from(data),
+            },
+        )
+        .build();
+        builder.exec(flash_mint_request).commit();
+
+        let error = builder.get_error().expect("should have error");
+        assert!(
+            matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == expected_error),
+            "{:?}",
+            error
+        );
+    }
+    assert_eq!(
+        cep18_check_total_supply(&mut builder, &cep18_token),
+        U256::from(TOKEN_TOTAL_SUPPLY)
+    );
+}
+
+#[test]
+fn should_not_flash_mint_unless_enabled() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_token,
+            cep18_test_contract_package,
+        },
+    ) = setup();
+
+    assert_eq!(
+        cep18_check_max_flash_loan(&mut builder, &cep18_token),
+        U256::zero()
+    );
+
+    let flash_mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_FLASH_MINT,
+        runtime_args! {
+            ARG_BORROWER => Key::from(cep18_test_contract_package),
+            ARG_AMOUNT => U256::from(FLASH_LOAN_AMOUNT),
+            ARG_DATA => Bytes::new(),
+        },
+    )
+    .build();
+    builder.exec(flash_mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_FLASH_MINT_DISABLED),
+        "{:?}",
+        error
+    );
+}
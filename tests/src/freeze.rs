@@ -0,0 +1,271 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ALLOWANCE_AMOUNT_1, ARG_ACCOUNT, ARG_AMOUNT, ARG_DECIMALS,
+        ARG_MINTS, ARG_NAME, ARG_OWNER, ARG_RECIPIENT, ARG_SYMBOL, ARG_TOTAL_SUPPLY, ENABLE_FREEZE,
+        ENABLE_MINT_BURN, ERROR_ACCOUNT_FROZEN, ERROR_FREEZE_DISABLED, ERROR_INSUFFICIENT_RIGHTS,
+        METHOD_BATCH_MINT, METHOD_FREEZE_ACCOUNT, METHOD_MINT, METHOD_TRANSFER_FROM,
+        METHOD_UNFREEZE_ACCOUNT, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+        TRANSFER_AMOUNT_1,
+    },
+    installer_request_builders::{
+        cep18_check_balance_of, make_cep18_approve_request, make_cep18_transfer_request,
+        setup_with_args, TestContext,
+    },
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+#[test]
+fn should_not_transfer_from_frozen_account() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_FREEZE => 1u8,
+    });
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_1_ADDR);
+
+    let freeze_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_FREEZE_ACCOUNT,
+        runtime_args! {
+            ARG_ACCOUNT => owner,
+        },
+    )
+    .build();
+    builder.exec(freeze_request).expect_success().commit();
+
+    let approve_request =
+        make_cep18_approve_request(owner, &cep18_token, recipient, ALLOWANCE_AMOUNT_1.into());
+    builder.exec(approve_request).expect_success().commit();
+
+    // Zero amounts must revert as well, including through `transfer_from`.
+    for amount in [U256::from(TRANSFER_AMOUNT_1), U256::zero()] {
+        let transfer_request = make_cep18_transfer_request(owner, &cep18_token, recipient, amount);
+        let transfer_from_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *ACCOUNT_1_ADDR,
+            cep18_token,
+            METHOD_TRANSFER_FROM,
+            runtime_args! {
+                ARG_OWNER => owner,
+                ARG_RECIPIENT => recipient,
+                ARG_AMOUNT => amount,
+            },
+        )
+        .build();
+        for request in [transfer_request, transfer_from_request] {
+            builder.exec(request).commit();
+
+            let error = builder.get_error().expect("should have error");
+            assert!(
+                matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_ACCOUNT_FROZEN),
+                "{:?}",
+                error
+            );
+        }
+    }
+
+    let unfreeze_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_UNFREEZE_ACCOUNT,
+        runtime_args! {
+            ARG_ACCOUNT => owner,
+        },
+    )
+    .build();
+    builder.exec(unfreeze_request).expect_success().commit();
+
+    let transfer_request = make_cep18_transfer_request(
+        owner,
+        &cep18_token,
+        recipient,
+        U256::from(TRANSFER_AMOUNT_1),
+    );
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, recipient),
+        U256::from(TRANSFER_AMOUNT_1)
+    );
+}
+
+#[test]
+fn should_not_transfer_to_frozen_account() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_FREEZE => 1u8,
+    });
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let spender = Key::Account(*ACCOUNT_2_ADDR);
+    let recipient = Key::Account(*ACCOUNT_1_ADDR);
+
+    let freeze_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_FREEZE_ACCOUNT,
+        runtime_args! {
+            ARG_ACCOUNT => recipient,
+        },
+    )
+    .build();
+    builder.exec(freeze_request).expect_success().commit();
+
+    let approve_request =
+        make_cep18_approve_request(owner, &cep18_token, spender, ALLOWANCE_AMOUNT_1.into());
+    builder.exec(approve_request).expect_success().commit();
+
+    // Zero amounts must revert as well, including through `transfer_from`.
+    for amount in [U256::from(TRANSFER_AMOUNT_1), U256::zero()] {
+        let transfer_request = make_cep18_transfer_request(owner, &cep18_token, recipient, amount);
+        let transfer_from_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *ACCOUNT_2_ADDR,
+            cep18_token,
+            METHOD_TRANSFER_FROM,
+            runtime_args! {
+                ARG_OWNER => owner,
+                ARG_RECIPIENT => recipient,
+                ARG_AMOUNT => amount,
+            },
+        )
+        .build();
+        for request in [transfer_request, transfer_from_request] {
+            builder.exec(request).commit();
+
+            let error = builder.get_error().expect("should have error");
+            assert!(
+                matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_ACCOUNT_FROZEN),
+                "{:?}",
+                error
+            );
+        }
+    }
+}
+
+#[test]
+fn should_not_mint_to_frozen_account() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_FREEZE => 1u8,
+        ENABLE_MINT_BURN => true,
+    });
+    let recipient = Key::Account(*ACCOUNT_1_ADDR);
+    let amount = U256::from(TRANSFER_AMOUNT_1);
+
+    let freeze_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_FREEZE_ACCOUNT,
+        runtime_args! {
+            ARG_ACCOUNT => recipient,
+        },
+    )
+    .build();
+    builder.exec(freeze_request).expect_success().commit();
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => recipient,
+            ARG_AMOUNT => amount,
+        },
+    )
+    .build();
+    let batch_mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_BATCH_MINT,
+        runtime_args! {
+            ARG_MINTS => vec![(recipient, amount)],
+        },
+    )
+    .build();
+    for request in [mint_request, batch_mint_request] {
+        builder.exec(request).commit();
+
+        let error = builder.get_error().expect("should have error");
+        assert!(
+            matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_ACCOUNT_FROZEN),
+            "{:?}",
+            error
+        );
+    }
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, recipient),
+        U256::zero()
+    );
+}
+
+#[test]
+fn should_not_freeze_without_admin_rights() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_FREEZE => 1u8,
+    });
+
+    let freeze_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_FREEZE_ACCOUNT,
+        runtime_args! {
+            ARG_ACCOUNT => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        },
+    )
+    .build();
+    builder.exec(freeze_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_RIGHTS),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_freeze_with_freeze_disabled() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+    });
+
+    let freeze_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_FREEZE_ACCOUNT,
+        runtime_args! {
+            ARG_ACCOUNT => Key::Account(*ACCOUNT_1_ADDR),
+        },
+    )
+    .build();
+    builder.exec(freeze_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_FREEZE_DISABLED),
+        "{:?}",
+        error
+    );
+}
@@ -1,12 +1,58 @@
 #[cfg(test)]
+mod admin_transfer;
+#[cfg(test)]
 mod allowance;
 #[cfg(test)]
+mod allowance_expiry;
+#[cfg(test)]
+mod allowlist;
+#[cfg(test)]
+mod batch_mint;
+#[cfg(test)]
+mod batch_transfer;
+#[cfg(test)]
+mod burn_from;
+#[cfg(test)]
+mod burner;
+#[cfg(test)]
+mod flash_mint;
+#[cfg(test)]
+mod freeze;
+#[cfg(test)]
 mod install;
 #[cfg(test)]
+mod max_supply;
+#[cfg(test)]
 mod migration;
 #[cfg(test)]
 mod mint_and_burn;
 #[cfg(test)]
+mod mint_rate_limit;
+#[cfg(test)]
+mod minter_allowance;
+#[cfg(test)]
+mod pause;
+#[cfg(test)]
+mod permit;
+#[cfg(test)]
+mod role_enumeration;
+#[cfg(test)]
+mod roles;
+#[cfg(test)]
+mod safe_transfer;
+#[cfg(test)]
+mod snapshots;
+#[cfg(test)]
 mod transfer;
 #[cfg(test)]
+mod transfer_and_call;
+#[cfg(test)]
+mod transfer_fee;
+#[cfg(test)]
+mod transfer_with_memo;
+#[cfg(test)]
 mod utility;
+#[cfg(test)]
+mod vesting;
+#[cfg(test)]
+mod votes;
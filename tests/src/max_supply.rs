@@ -0,0 +1,165 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, ApiError, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ARG_AMOUNT, ARG_DECIMALS, ARG_MAX_SUPPLY, ARG_NAME, ARG_OWNER, ARG_SYMBOL,
+        ARG_TOTAL_SUPPLY, ENABLE_MINT_BURN, ERROR_EXCEEDS_MAX_SUPPLY, ERROR_INSUFFICIENT_RIGHTS,
+        ERROR_INVALID_MAX_SUPPLY, MAX_SUPPLY_KEY, METHOD_LOWER_MAX_SUPPLY, METHOD_MINT,
+        TOKEN_DECIMALS, TOKEN_NAME, TOKEN_OWNER_ADDRESS_1, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+    },
+    installer_request_builders::{cep18_check_total_supply, setup, setup_with_args, TestContext},
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+#[test]
+fn should_default_to_unbounded_max_supply() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    let max_supply: U256 = builder.get_value(cep18_token, MAX_SUPPLY_KEY);
+    assert_eq!(max_supply, U256::MAX);
+}
+
+#[test]
+fn should_not_mint_above_max_supply() {
+    let max_supply = U256::from(TOKEN_TOTAL_SUPPLY) + 10;
+
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ARG_MAX_SUPPLY => max_supply,
+        ENABLE_MINT_BURN => true,
+    });
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => U256::from(10),
+        },
+    )
+    .build();
+    builder.exec(mint_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_total_supply(&mut builder, &cep18_token),
+        max_supply
+    );
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => U256::one(),
+        },
+    )
+    .build();
+    builder.exec(mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_EXCEEDS_MAX_SUPPLY),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_only_lower_max_supply() {
+    let max_supply = U256::from(TOKEN_TOTAL_SUPPLY) + 10;
+
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ARG_MAX_SUPPLY => max_supply,
+    });
+
+    let raise_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_LOWER_MAX_SUPPLY,
+        runtime_args! {
+            ARG_MAX_SUPPLY => max_supply + 1,
+        },
+    )
+    .build();
+    builder.exec(raise_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INVALID_MAX_SUPPLY),
+        "{:?}",
+        error
+    );
+
+    let below_total_supply_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_LOWER_MAX_SUPPLY,
+        runtime_args! {
+            ARG_MAX_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY) - 1,
+        },
+    )
+    .build();
+    builder.exec(below_total_supply_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INVALID_MAX_SUPPLY),
+        "{:?}",
+        error
+    );
+
+    let lower_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_LOWER_MAX_SUPPLY,
+        runtime_args! {
+            ARG_MAX_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        },
+    )
+    .build();
+    builder.exec(lower_request).expect_success().commit();
+
+    let max_supply: U256 = builder.get_value(cep18_token, MAX_SUPPLY_KEY);
+    assert_eq!(max_supply, U256::from(TOKEN_TOTAL_SUPPLY));
+}
+
+#[test]
+fn should_not_lower_max_supply_without_admin_rights() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+    });
+
+    let lower_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_LOWER_MAX_SUPPLY,
+        runtime_args! {
+            ARG_MAX_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        },
+    )
+    .build();
+    builder.exec(lower_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_RIGHTS),
+        "{:?}",
+        error
+    );
+}
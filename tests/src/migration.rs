@@ -1,12 +1,19 @@
 use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
-use casper_types::{runtime_args, Key, RuntimeArgs, U256};
+use casper_event_standard::{Schemas, EVENTS_SCHEMA};
+use casper_types::{runtime_args, ApiError, ContractHash, Key, RuntimeArgs, U256};
 
 use crate::utility::{
     constants::{
-        ARG_DECIMALS, ARG_NAME, ARG_SYMBOL, ARG_TOTAL_SUPPLY, CEP18_CONTRACT_WASM, TOKEN_DECIMALS,
-        TOKEN_NAME, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+        ACCOUNT_1_ADDR, ADMIN_LIST, ARG_ACCOUNT, ARG_DECIMALS, ARG_NAME, ARG_ROLE, ARG_SYMBOL,
+        ARG_TOTAL_SUPPLY, CEP18_CONTRACT_WASM, CEP18_TOKEN_CONTRACT_KEY, ERROR_LAST_ADMIN,
+        EVENTS_LENGTH_KEY, EVENTS_MODE, METHOD_REVOKE_ROLE, ROLE_ADMIN, TOKEN_DECIMALS, TOKEN_NAME,
+        TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
     },
-    installer_request_builders::{setup, TestContext},
+    installer_request_builders::{cep18_check_has_role, setup, setup_1_1_0_with_args, TestContext},
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
 };
 
 #[test]
@@ -55,3 +62,135 @@ fn should_upgrade_contract_version() {
 
     assert!(version_0 < version_1);
 }
+
+#[test]
+fn should_store_current_events_schema_on_upgrade() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_1_1_0_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        EVENTS_MODE => 1u8,
+    });
+    let events_length: u32 = builder.get_value(cep18_token, EVENTS_LENGTH_KEY);
+    let schemas: Schemas = builder.get_value(cep18_token, EVENTS_SCHEMA);
+    assert!(!schemas.0.contains_key("BatchTransfer"));
+
+    let upgrade_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CEP18_CONTRACT_WASM,
+        runtime_args! {
+            ARG_NAME => TOKEN_NAME,
+        },
+    )
+    .build();
+    builder.exec(upgrade_request).expect_success().commit();
+
+    let upgraded_token = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(CEP18_TOKEN_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .expect("should have upgraded contract hash");
+    assert_ne!(upgraded_token, cep18_token);
+
+    let schemas: Schemas = builder.get_value(upgraded_token, EVENTS_SCHEMA);
+    let field_names = |event: &str| -> Vec<String> {
+        schemas
+            .0
+            .get(event)
+            .unwrap_or_else(|| panic!("should have {} schema", event))
+            .clone()
+            .to_vec()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect()
+    };
+    assert!(field_names("SetAllowance").contains(&"expires_at".to_string()));
+    assert!(field_names("IncreaseAllowance").contains(&"expires_at".to_string()));
+    assert!(field_names("Burn").contains(&"spender".to_string()));
+    // Events added after the first version are registered as well.
+    for event in ["BatchTransfer", "RoleGranted", "FeeCharged", "FlashMint"] {
+        assert!(
+            schemas.0.contains_key(event),
+            "should have {} schema",
+            event
+        );
+    }
+
+    // The events recorded before the upgrade are kept.
+    let upgraded_events_length: u32 = builder.get_value(upgraded_token, EVENTS_LENGTH_KEY);
+    assert_eq!(upgraded_events_length, events_length);
+}
+
+#[test]
+fn should_not_revoke_own_admin_role_after_upgrade() {
+    let (mut builder, _) = setup_1_1_0_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ADMIN_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+    });
+    let admin = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    let upgrade_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CEP18_CONTRACT_WASM,
+        runtime_args! {
+            ARG_NAME => TOKEN_NAME,
+        },
+    )
+    .build();
+    builder.exec(upgrade_request).expect_success().commit();
+    let cep18_token = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(CEP18_TOKEN_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .expect("should have upgraded contract hash");
+
+    // Without an admin count the contract cannot tell whether another Admin is left.
+    let revoke_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_REVOKE_ROLE,
+        runtime_args! {
+            ARG_ACCOUNT => admin,
+            ARG_ROLE => ROLE_ADMIN,
+        },
+    )
+    .build();
+    builder.exec(revoke_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_LAST_ADMIN),
+        "{:?}",
+        error
+    );
+    assert!(cep18_check_has_role(&mut builder, admin, ROLE_ADMIN));
+
+    // Revoking the Admin role of another account is still possible.
+    let revoke_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_REVOKE_ROLE,
+        runtime_args! {
+            ARG_ACCOUNT => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_ROLE => ROLE_ADMIN,
+        },
+    )
+    .build();
+    builder.exec(revoke_request).expect_success().commit();
+
+    assert!(!cep18_check_has_role(
+        &mut builder,
+        Key::Account(*ACCOUNT_1_ADDR),
+        ROLE_ADMIN
+    ));
+}
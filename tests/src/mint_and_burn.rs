@@ -3,10 +3,11 @@ use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
 
 use crate::utility::{
     constants::{
-        ACCOUNT_1_ADDR, ADMIN_LIST, AMOUNT, ARG_AMOUNT, ARG_DECIMALS, ARG_NAME, ARG_OWNER,
-        ARG_SYMBOL, ARG_TOTAL_SUPPLY, CHANGE_SECURITY, ENABLE_MINT_BURN,
-        ERROR_INSUFFICIENT_BALANCE, ERROR_OVERFLOW, METHOD_BURN, METHOD_MINT, MINTER_LIST,
-        NONE_LIST, OWNER, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_OWNER_ADDRESS_1, TOKEN_OWNER_ADDRESS_2,
+        ACCOUNT_1_ADDR, ADMIN_LIST, AMOUNT, ARG_ACCOUNT, ARG_AMOUNT, ARG_DECIMALS, ARG_NAME,
+        ARG_OWNER, ARG_SYMBOL, ARG_TOTAL_SUPPLY, CHANGE_SECURITY, ENABLE_MINT_BURN, ENABLE_PAUSE,
+        ERROR_INSUFFICIENT_BALANCE, ERROR_OVERFLOW, METHOD_ACCEPT_ADMIN, METHOD_BURN, METHOD_MINT,
+        METHOD_PAUSE, METHOD_PROPOSE_ADMIN, METHOD_RENOUNCE_ADMIN, MINTER_LIST, NONE_LIST, OWNER,
+        TOKEN_DECIMALS, TOKEN_NAME, TOKEN_OWNER_ADDRESS_1, TOKEN_OWNER_ADDRESS_2,
         TOKEN_OWNER_AMOUNT_1, TOKEN_OWNER_AMOUNT_2, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
     },
     installer_request_builders::{
@@ -462,3 +463,144 @@ fn test_change_security() {
         error
     );
 }
+
+#[test]
+fn test_change_security_on_fixed_supply_token() {
+    let mint_amount = U256::one();
+
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => false,
+        ADMIN_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)]
+    });
+
+    // admins can be rotated even though minting and burning are disabled
+    let change_security_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        CHANGE_SECURITY,
+        runtime_args! {
+            NONE_LIST => vec![Key::Account(*DEFAULT_ACCOUNT_ADDR)],
+        },
+    )
+    .build();
+
+    builder
+        .exec(change_security_request)
+        .commit()
+        .expect_success();
+
+    let change_security_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        CHANGE_SECURITY,
+        runtime_args! {
+            ADMIN_LIST => vec![Key::Account(*DEFAULT_ACCOUNT_ADDR)],
+        },
+    )
+    .build();
+
+    builder.exec(change_security_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == 60010),
+        "{:?}",
+        error
+    );
+
+    // minting stays disabled, even for admins
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => mint_amount,
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == 60016),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn test_transfer_admin_on_fixed_supply_token() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_PAUSE => 1u8,
+    });
+
+    let propose_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_PROPOSE_ADMIN,
+        runtime_args! {
+            ARG_ACCOUNT => Key::Account(*ACCOUNT_1_ADDR),
+        },
+    )
+    .build();
+
+    builder.exec(propose_request).commit().expect_success();
+
+    let accept_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_ACCEPT_ADMIN,
+        runtime_args! {},
+    )
+    .build();
+
+    builder.exec(accept_request).commit().expect_success();
+
+    let renounce_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_RENOUNCE_ADMIN,
+        runtime_args! {},
+    )
+    .build();
+
+    builder.exec(renounce_request).commit().expect_success();
+
+    // the new admin manages the other admin features of the token
+    let pause_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_PAUSE,
+        runtime_args! {},
+    )
+    .build();
+
+    builder.exec(pause_request).commit().expect_success();
+
+    let pause_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_PAUSE,
+        runtime_args! {},
+    )
+    .build();
+
+    builder.exec(pause_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == 60010),
+        "{:?}",
+        error
+    );
+}
@@ -0,0 +1,310 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{
+    account::AccountHash, runtime_args, ApiError, ContractHash, Key, RuntimeArgs, U256,
+};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ARG_AMOUNT, ARG_DECIMALS, ARG_MINTS, ARG_MINT_RATE_LIMIT,
+        ARG_MINT_RATE_WINDOW, ARG_NAME, ARG_OWNER, ARG_SYMBOL, ARG_TOTAL_SUPPLY, ENABLE_MINT_BURN,
+        ERROR_EXCEEDS_MINT_RATE_LIMIT, METHOD_BATCH_MINT, METHOD_MINT, METHOD_SET_MINT_RATE_LIMIT,
+        MINTER_LIST, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_OWNER_ADDRESS_1, TOKEN_SYMBOL,
+        TOKEN_TOTAL_SUPPLY,
+    },
+    installer_request_builders::{
+        cep18_check_balance_of, cep18_check_total_supply, setup_with_args, TestContext,
+    },
+};
+
+use casper_execution_engine::core::{
+    engine_state::{Error as CoreError, ExecuteRequest},
+    execution::Error as ExecError,
+};
+
+const MINT_RATE_WINDOW: u64 = 1_000;
+
+fn make_mint_request(
+    sender: AccountHash,
+    cep18_token: ContractHash,
+    amount: U256,
+    block_time: u64,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => amount,
+        },
+    )
+    .with_block_time(block_time)
+    .build()
+}
+
+#[test]
+fn should_limit_minting_per_window() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        MINTER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+        ARG_MINT_RATE_LIMIT => U256::from(100),
+        ARG_MINT_RATE_WINDOW => MINT_RATE_WINDOW,
+    });
+    let minter = Key::Account(*ACCOUNT_1_ADDR);
+
+    let mint_request = make_mint_request(*ACCOUNT_1_ADDR, cep18_token, U256::from(60), 0);
+    builder.exec(mint_request).expect_success().commit();
+
+    let batch_mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_BATCH_MINT,
+        runtime_args! {
+            ARG_MINTS => vec![(TOKEN_OWNER_ADDRESS_1, U256::from(30)), (minter, U256::from(20))],
+        },
+    )
+    .with_block_time(MINT_RATE_WINDOW / 2)
+    .build();
+    builder.exec(batch_mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_EXCEEDS_MINT_RATE_LIMIT),
+        "{:?}",
+        error
+    );
+
+    let mint_request = make_mint_request(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        U256::from(40),
+        MINT_RATE_WINDOW - 1,
+    );
+    builder.exec(mint_request).expect_success().commit();
+
+    let mint_request = make_mint_request(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        U256::one(),
+        MINT_RATE_WINDOW - 1,
+    );
+    builder.exec(mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_EXCEEDS_MINT_RATE_LIMIT),
+        "{:?}",
+        error
+    );
+
+    // Only the first mint has left the window.
+    let mint_request = make_mint_request(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        U256::from(60),
+        MINT_RATE_WINDOW,
+    );
+    builder.exec(mint_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, TOKEN_OWNER_ADDRESS_1),
+        U256::from(160)
+    );
+}
+
+#[test]
+fn should_limit_minting_across_window_boundaries() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        MINTER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+        ARG_MINT_RATE_LIMIT => U256::from(100),
+        ARG_MINT_RATE_WINDOW => MINT_RATE_WINDOW,
+    });
+
+    let mint_request = make_mint_request(*ACCOUNT_1_ADDR, cep18_token, U256::one(), 0);
+    builder.exec(mint_request).expect_success().commit();
+
+    let mint_request = make_mint_request(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        U256::from(99),
+        MINT_RATE_WINDOW - 50,
+    );
+    builder.exec(mint_request).expect_success().commit();
+
+    // A window opened by the first mint would have elapsed, but the second mint is still within
+    // the last window.
+    let mint_request = make_mint_request(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        U256::from(100),
+        MINT_RATE_WINDOW + 50,
+    );
+    builder.exec(mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_EXCEEDS_MINT_RATE_LIMIT),
+        "{:?}",
+        error
+    );
+
+    let mint_request = make_mint_request(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        U256::one(),
+        MINT_RATE_WINDOW + 50,
+    );
+    builder.exec(mint_request).expect_success().commit();
+
+    let mint_request = make_mint_request(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        U256::from(99),
+        2 * MINT_RATE_WINDOW,
+    );
+    builder.exec(mint_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, TOKEN_OWNER_ADDRESS_1),
+        U256::from(200)
+    );
+}
+
+#[test]
+fn should_not_limit_minting_past_widened_window() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        MINTER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+        ARG_MINT_RATE_LIMIT => U256::from(100),
+        ARG_MINT_RATE_WINDOW => MINT_RATE_WINDOW,
+    });
+    let widened_window = 100 * MINT_RATE_WINDOW;
+    let last_mint_time = 10 * MINT_RATE_WINDOW - 50;
+
+    let mint_request = make_mint_request(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        U256::from(100),
+        last_mint_time,
+    );
+    builder.exec(mint_request).expect_success().commit();
+
+    let set_rate_limit_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_SET_MINT_RATE_LIMIT,
+        runtime_args! {
+            ARG_MINT_RATE_LIMIT => U256::from(100),
+            ARG_MINT_RATE_WINDOW => widened_window,
+        },
+    )
+    .with_block_time(last_mint_time)
+    .build();
+    builder
+        .exec(set_rate_limit_request)
+        .expect_success()
+        .commit();
+
+    // The bucket of the last mint is ahead of the buckets of the widened window, and must not
+    // keep counting once a whole widened window has elapsed.
+    let mint_request = make_mint_request(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        U256::from(100),
+        last_mint_time + widened_window,
+    );
+    builder.exec(mint_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, TOKEN_OWNER_ADDRESS_1),
+        U256::from(200)
+    );
+}
+
+#[test]
+fn should_set_mint_rate_limit_and_not_limit_admins() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        MINTER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+    });
+
+    let set_rate_limit_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_SET_MINT_RATE_LIMIT,
+        runtime_args! {
+            ARG_MINT_RATE_LIMIT => U256::from(10),
+            ARG_MINT_RATE_WINDOW => MINT_RATE_WINDOW,
+        },
+    )
+    .build();
+    builder.exec(set_rate_limit_request).expect_failure();
+
+    let set_rate_limit_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_SET_MINT_RATE_LIMIT,
+        runtime_args! {
+            ARG_MINT_RATE_LIMIT => U256::from(10),
+            ARG_MINT_RATE_WINDOW => MINT_RATE_WINDOW,
+        },
+    )
+    .build();
+    builder
+        .exec(set_rate_limit_request)
+        .expect_success()
+        .commit();
+
+    let mint_request = make_mint_request(*DEFAULT_ACCOUNT_ADDR, cep18_token, U256::from(100), 0);
+    builder.exec(mint_request).expect_success().commit();
+
+    let mint_request = make_mint_request(*ACCOUNT_1_ADDR, cep18_token, U256::from(11), 0);
+    builder.exec(mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_EXCEEDS_MINT_RATE_LIMIT),
+        "{:?}",
+        error
+    );
+
+    let lift_rate_limit_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_SET_MINT_RATE_LIMIT,
+        runtime_args! {
+            ARG_MINT_RATE_LIMIT => U256::zero(),
+            ARG_MINT_RATE_WINDOW => 0u64,
+        },
+    )
+    .build();
+    builder
+        .exec(lift_rate_limit_request)
+        .expect_success()
+        .commit();
+
+    let mint_request = make_mint_request(*ACCOUNT_1_ADDR, cep18_token, U256::from(11), 0);
+    builder.exec(mint_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_total_supply(&mut builder, &cep18_token),
+        U256::from(TOKEN_TOTAL_SUPPLY) + U256::from(111)
+    );
+}
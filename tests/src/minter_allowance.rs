@@ -0,0 +1,160 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ARG_ALLOWANCE, ARG_AMOUNT, ARG_DECIMALS, ARG_MINTER, ARG_MINTS, ARG_NAME,
+        ARG_OWNER, ARG_SYMBOL, ARG_TOTAL_SUPPLY, ENABLE_MINT_BURN, ERROR_EXCEEDS_MINTER_ALLOWANCE,
+        METHOD_BATCH_MINT, METHOD_MINT, METHOD_SET_MINTER_ALLOWANCE, MINTER_LIST, TOKEN_DECIMALS,
+        TOKEN_NAME, TOKEN_OWNER_ADDRESS_1, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+    },
+    installer_request_builders::{
+        cep18_check_balance_of, cep18_check_total_supply, setup_with_args, TestContext,
+    },
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+#[test]
+fn should_cap_minting_to_minter_allowance() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        MINTER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+    });
+    let minter = Key::Account(*ACCOUNT_1_ADDR);
+
+    let set_allowance_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_SET_MINTER_ALLOWANCE,
+        runtime_args! {
+            ARG_MINTER => minter,
+            ARG_ALLOWANCE => Some(U256::from(100)),
+        },
+    )
+    .build();
+    builder
+        .exec(set_allowance_request)
+        .expect_success()
+        .commit();
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => U256::from(60),
+        },
+    )
+    .build();
+    builder.exec(mint_request).expect_success().commit();
+
+    let batch_mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_BATCH_MINT,
+        runtime_args! {
+            ARG_MINTS => vec![(TOKEN_OWNER_ADDRESS_1, U256::from(30)), (minter, U256::from(30))],
+        },
+    )
+    .build();
+    builder.exec(batch_mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_EXCEEDS_MINTER_ALLOWANCE),
+        "{:?}",
+        error
+    );
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => U256::from(40),
+        },
+    )
+    .build();
+    builder.exec(mint_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, TOKEN_OWNER_ADDRESS_1),
+        U256::from(100)
+    );
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => U256::one(),
+        },
+    )
+    .build();
+    builder.exec(mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_EXCEEDS_MINTER_ALLOWANCE),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_cap_admins_or_minters_without_allowance() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        MINTER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+    });
+    let admin = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let mint_amount = U256::from(1_000);
+
+    let set_allowance_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_SET_MINTER_ALLOWANCE,
+        runtime_args! {
+            ARG_MINTER => admin,
+            ARG_ALLOWANCE => Some(U256::zero()),
+        },
+    )
+    .build();
+    builder
+        .exec(set_allowance_request)
+        .expect_success()
+        .commit();
+
+    for sender in [*DEFAULT_ACCOUNT_ADDR, *ACCOUNT_1_ADDR] {
+        let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+            sender,
+            cep18_token,
+            METHOD_MINT,
+            runtime_args! {
+                ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+                ARG_AMOUNT => mint_amount,
+            },
+        )
+        .build();
+        builder.exec(mint_request).expect_success().commit();
+    }
+
+    assert_eq!(
+        cep18_check_total_supply(&mut builder, &cep18_token),
+        U256::from(TOKEN_TOTAL_SUPPLY) + mint_amount * 2
+    );
+}
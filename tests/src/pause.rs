@@ -0,0 +1,213 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_AMOUNT, ARG_DECIMALS, ARG_NAME, ARG_OWNER,
+        ARG_RECIPIENT, ARG_SPENDER, ARG_SYMBOL, ARG_TOTAL_SUPPLY, ENABLE_MINT_BURN, ENABLE_PAUSE,
+        ERROR_CONTRACT_PAUSED, ERROR_INSUFFICIENT_RIGHTS, ERROR_PAUSE_DISABLED, METHOD_APPROVE,
+        METHOD_MINT, METHOD_PAUSE, METHOD_TRANSFER, METHOD_UNPAUSE, PAUSER_LIST, TOKEN_DECIMALS,
+        TOKEN_NAME, TOKEN_OWNER_ADDRESS_1, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY, TRANSFER_AMOUNT_1,
+    },
+    installer_request_builders::{cep18_check_balance_of, setup_with_args, TestContext},
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+#[test]
+fn should_not_transfer_while_paused() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_PAUSE => 1u8,
+    });
+
+    let pause_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_PAUSE,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(pause_request).expect_success().commit();
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_AMOUNT => U256::from(TRANSFER_AMOUNT_1),
+        },
+    )
+    .build();
+    builder.exec(transfer_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_CONTRACT_PAUSED),
+        "{:?}",
+        error
+    );
+
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_APPROVE,
+        runtime_args! {
+            ARG_SPENDER => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_AMOUNT => U256::from(TRANSFER_AMOUNT_1),
+        },
+    )
+    .build();
+    builder.exec(approve_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_CONTRACT_PAUSED),
+        "{:?}",
+        error
+    );
+
+    let unpause_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_UNPAUSE,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(unpause_request).expect_success().commit();
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_AMOUNT => U256::from(TRANSFER_AMOUNT_1),
+        },
+    )
+    .build();
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_1_ADDR)),
+        U256::from(TRANSFER_AMOUNT_1)
+    );
+}
+
+#[test]
+fn should_not_mint_while_paused() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        ENABLE_PAUSE => 1u8,
+    });
+
+    let pause_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_PAUSE,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(pause_request).expect_success().commit();
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => U256::one(),
+        },
+    )
+    .build();
+    builder.exec(mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_CONTRACT_PAUSED),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_only_allow_pausers_and_admins_to_pause() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_PAUSE => 1u8,
+        PAUSER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+    });
+
+    let pause_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_2_ADDR,
+        cep18_token,
+        METHOD_PAUSE,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(pause_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_RIGHTS),
+        "{:?}",
+        error
+    );
+
+    let pause_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_PAUSE,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(pause_request).expect_success().commit();
+
+    let unpause_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_UNPAUSE,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(unpause_request).expect_success().commit();
+}
+
+#[test]
+fn should_not_pause_with_pause_disabled() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+    });
+
+    let pause_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_PAUSE,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(pause_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_PAUSE_DISABLED),
+        "{:?}",
+        error
+    );
+}
@@ -0,0 +1,253 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    DEFAULT_ACCOUNT_PUBLIC_KEY,
+};
+use casper_types::{
+    bytesrepr::{Bytes, ToBytes},
+    crypto, runtime_args, ApiError, ContractHash, Key, PublicKey, RuntimeArgs, SecretKey, U256,
+};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_1_PUBLIC_KEY, ACCOUNT_1_SECRET_KEY, ALLOWANCE_AMOUNT_1, ARG_AMOUNT,
+        ARG_DEADLINE, ARG_DECIMALS, ARG_NAME, ARG_NONCE, ARG_OWNER, ARG_SIGNATURE, ARG_SPENDER,
+        ARG_SYMBOL, ARG_TOTAL_SUPPLY, ERROR_INVALID_NONCE, ERROR_INVALID_SIGNATURE,
+        ERROR_PERMIT_EXPIRED, METHOD_PERMIT, PERMIT_MESSAGE_PREFIX, TOKEN_DECIMALS, TOKEN_NAME,
+        TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+    },
+    installer_request_builders::{cep18_check_allowance_of, setup_with_builder, TestContext},
+};
+
+use casper_execution_engine::core::{
+    engine_state::{EngineConfig, Error as CoreError, DEFAULT_MAX_QUERY_DEPTH},
+    execution::Error as ExecError,
+};
+
+const DEADLINE: u64 = 1_000;
+
+/// Signature verification runs inside the contract and needs a deeper Wasm stack than the
+/// 200 allowed by the bundled production chainspec, so these tests raise it to 500.
+const PERMIT_MAX_STACK_HEIGHT: u32 = 500;
+
+fn setup() -> (InMemoryWasmTestBuilder, TestContext) {
+    let production_config = *InMemoryWasmTestBuilder::default()
+        .get_engine_state()
+        .config();
+    let mut wasm_config = *production_config.wasm_config();
+    wasm_config.max_stack_height = PERMIT_MAX_STACK_HEIGHT;
+    let engine_config = EngineConfig::new(
+        DEFAULT_MAX_QUERY_DEPTH,
+        production_config.max_associated_keys(),
+        production_config.max_runtime_call_stack_height(),
+        production_config.minimum_delegation_amount(),
+        production_config.strict_argument_checking(),
+        production_config.vesting_schedule_period_millis(),
+        production_config.max_delegators_per_validator(),
+        wasm_config,
+        *production_config.system_config(),
+    );
+
+    setup_with_builder(
+        InMemoryWasmTestBuilder::new_with_config(engine_config),
+        runtime_args! {
+            ARG_NAME => TOKEN_NAME,
+            ARG_SYMBOL => TOKEN_SYMBOL,
+            ARG_DECIMALS => TOKEN_DECIMALS,
+            ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        },
+    )
+}
+
+/// Signs a permit the way an off-chain wallet would, returning the raw 64-byte signature.
+fn sign_permit(
+    builder: &InMemoryWasmTestBuilder,
+    cep18_token: ContractHash,
+    secret_key: &SecretKey,
+    public_key: &PublicKey,
+    spender: Key,
+    amount: U256,
+    nonce: u64,
+) -> Bytes {
+    let package_hash = Key::from(
+        builder
+            .get_contract(cep18_token)
+            .expect("should have contract")
+            .contract_package_hash(),
+    );
+
+    let mut preimage = Vec::new();
+    preimage.extend_from_slice(PERMIT_MESSAGE_PREFIX.as_bytes());
+    preimage.append(&mut package_hash.to_bytes().unwrap());
+    preimage.append(&mut public_key.to_bytes().unwrap());
+    preimage.append(&mut spender.to_bytes().unwrap());
+    preimage.append(&mut amount.to_bytes().unwrap());
+    preimage.append(&mut DEADLINE.to_bytes().unwrap());
+    preimage.append(&mut nonce.to_bytes().unwrap());
+    let message = crypto::blake2b(preimage);
+
+    // Drop the leading tag byte of the serialized signature.
+    let signature = crypto::sign(message, secret_key, public_key);
+    Bytes::from(signature.to_bytes().unwrap()[1..].to_vec())
+}
+
+#[test]
+fn should_approve_with_secp256k1_permit_once() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let owner = Key::Account(*ACCOUNT_1_ADDR);
+    let spender = Key::Hash([42; 32]);
+    let allowance_amount = U256::from(ALLOWANCE_AMOUNT_1);
+
+    let signature = sign_permit(
+        &builder,
+        cep18_token,
+        &ACCOUNT_1_SECRET_KEY,
+        &ACCOUNT_1_PUBLIC_KEY,
+        spender,
+        allowance_amount,
+        0,
+    );
+    let permit_args = runtime_args! {
+        ARG_OWNER => ACCOUNT_1_PUBLIC_KEY.clone(),
+        ARG_SPENDER => spender,
+        ARG_AMOUNT => allowance_amount,
+        ARG_DEADLINE => DEADLINE,
+        ARG_NONCE => 0u64,
+        ARG_SIGNATURE => signature,
+    };
+
+    // Anyone can relay the owner's signed approval.
+    let permit_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_PERMIT,
+        permit_args.clone(),
+    )
+    .build();
+    builder.exec(permit_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_allowance_of(&mut builder, owner, spender),
+        allowance_amount
+    );
+
+    let replay_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_PERMIT,
+        permit_args,
+    )
+    .build();
+    builder.exec(replay_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INVALID_NONCE),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_approve_with_ed25519_permit() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let spender = Key::Hash([42; 32]);
+    let allowance_amount = U256::from(ALLOWANCE_AMOUNT_1);
+    let secret_key = SecretKey::ed25519_from_bytes([199; SecretKey::ED25519_LENGTH]).unwrap();
+
+    let signature = sign_permit(
+        &builder,
+        cep18_token,
+        &secret_key,
+        &DEFAULT_ACCOUNT_PUBLIC_KEY,
+        spender,
+        allowance_amount,
+        0,
+    );
+    let permit_args = runtime_args! {
+        ARG_OWNER => DEFAULT_ACCOUNT_PUBLIC_KEY.clone(),
+        ARG_SPENDER => spender,
+        ARG_AMOUNT => allowance_amount,
+        ARG_DEADLINE => DEADLINE,
+        ARG_NONCE => 0u64,
+        ARG_SIGNATURE => signature,
+    };
+    let permit_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_PERMIT,
+        permit_args,
+    )
+    .build();
+    builder.exec(permit_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_allowance_of(&mut builder, owner, spender),
+        allowance_amount
+    );
+}
+
+#[test]
+fn should_not_approve_with_invalid_permit() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let spender = Key::Hash([42; 32]);
+    let allowance_amount = U256::from(ALLOWANCE_AMOUNT_1);
+
+    let signature = sign_permit(
+        &builder,
+        cep18_token,
+        &ACCOUNT_1_SECRET_KEY,
+        &ACCOUNT_1_PUBLIC_KEY,
+        spender,
+        allowance_amount,
+        0,
+    );
+
+    let expired_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_PERMIT,
+        runtime_args! {
+            ARG_OWNER => ACCOUNT_1_PUBLIC_KEY.clone(),
+            ARG_SPENDER => spender,
+            ARG_AMOUNT => allowance_amount,
+            ARG_DEADLINE => DEADLINE,
+            ARG_NONCE => 0u64,
+            ARG_SIGNATURE => signature.clone(),
+        },
+    )
+    .with_block_time(DEADLINE + 1)
+    .build();
+    builder.exec(expired_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_PERMIT_EXPIRED),
+        "{:?}",
+        error
+    );
+
+    // The signature covers the amount, so raising it invalidates the permit.
+    let tampered_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_PERMIT,
+        runtime_args! {
+            ARG_OWNER => ACCOUNT_1_PUBLIC_KEY.clone(),
+            ARG_SPENDER => spender,
+            ARG_AMOUNT => allowance_amount + 1,
+            ARG_DEADLINE => DEADLINE,
+            ARG_NONCE => 0u64,
+            ARG_SIGNATURE => signature,
+        },
+    )
+    .build();
+    builder.exec(tampered_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INVALID_SIGNATURE),
+        "{:?}",
+        error
+    );
+}
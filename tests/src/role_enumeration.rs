@@ -0,0 +1,259 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, ContractHash, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ADMIN_LIST, ARG_ACCOUNT, ARG_ACCOUNTS, ARG_DECIMALS,
+        ARG_NAME, ARG_ROLE, ARG_SYMBOL, ARG_TOTAL_SUPPLY, CEP18_CONTRACT_WASM,
+        CEP18_TOKEN_CONTRACT_KEY, CHANGE_SECURITY, ENABLE_MINT_BURN, METHOD_INDEX_ROLES,
+        METHOD_REVOKE_ROLE, MINTER_LIST, NONE_LIST, ROLE_ADMIN, ROLE_MINTER, ROLE_NONE,
+        TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+    },
+    installer_request_builders::{
+        cep18_check_admins, cep18_check_badge, cep18_check_minters, cep18_check_role_members,
+        setup_1_1_0_with_args, setup_with_args, TestContext,
+    },
+};
+
+#[test]
+fn should_list_role_members_by_page() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        MINTER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR), Key::Account(*ACCOUNT_2_ADDR)],
+        ADMIN_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+    });
+
+    assert_eq!(
+        cep18_check_role_members(&mut builder, ROLE_ADMIN, 0, 10),
+        vec![
+            Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            Key::Account(*ACCOUNT_1_ADDR)
+        ]
+    );
+    assert_eq!(
+        cep18_check_role_members(&mut builder, ROLE_MINTER, 1, 1),
+        vec![Key::Account(*ACCOUNT_2_ADDR)]
+    );
+    assert!(cep18_check_role_members(&mut builder, ROLE_MINTER, 5, 10).is_empty());
+
+    let revoke_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_REVOKE_ROLE,
+        runtime_args! {
+            ARG_ACCOUNT => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_ROLE => ROLE_MINTER,
+        },
+    )
+    .build();
+    builder.exec(revoke_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_role_members(&mut builder, ROLE_MINTER, 0, 10),
+        vec![Key::Account(*ACCOUNT_2_ADDR)]
+    );
+
+    let change_security_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        CHANGE_SECURITY,
+        runtime_args! {
+            NONE_LIST => vec![Key::Account(*ACCOUNT_2_ADDR)],
+        },
+    )
+    .build();
+    builder
+        .exec(change_security_request)
+        .expect_success()
+        .commit();
+
+    assert!(cep18_check_role_members(&mut builder, ROLE_MINTER, 0, 10).is_empty());
+}
+
+#[test]
+fn should_list_admins_and_minters_after_security_changes() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+    });
+
+    assert_eq!(
+        cep18_check_admins(&mut builder, 0, 10),
+        vec![Key::Account(*DEFAULT_ACCOUNT_ADDR)]
+    );
+    assert!(cep18_check_minters(&mut builder, 0, 10).is_empty());
+
+    let change_security_request = |args: RuntimeArgs| {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            cep18_token,
+            CHANGE_SECURITY,
+            args,
+        )
+        .build()
+    };
+
+    builder
+        .exec(change_security_request(runtime_args! {
+            ADMIN_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+            MINTER_LIST => vec![Key::Account(*ACCOUNT_2_ADDR)],
+        }))
+        .expect_success()
+        .commit();
+    assert_eq!(
+        cep18_check_admins(&mut builder, 0, 10),
+        vec![
+            Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            Key::Account(*ACCOUNT_1_ADDR)
+        ]
+    );
+    assert_eq!(
+        cep18_check_admins(&mut builder, 1, 1),
+        vec![Key::Account(*ACCOUNT_1_ADDR)]
+    );
+    assert_eq!(
+        cep18_check_minters(&mut builder, 0, 10),
+        vec![Key::Account(*ACCOUNT_2_ADDR)]
+    );
+
+    builder
+        .exec(change_security_request(runtime_args! {
+            MINTER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+        }))
+        .expect_success()
+        .commit();
+    assert_eq!(
+        cep18_check_admins(&mut builder, 0, 10),
+        vec![Key::Account(*DEFAULT_ACCOUNT_ADDR)]
+    );
+    assert_eq!(
+        cep18_check_minters(&mut builder, 0, 10),
+        vec![Key::Account(*ACCOUNT_2_ADDR), Key::Account(*ACCOUNT_1_ADDR)]
+    );
+
+    builder
+        .exec(change_security_request(runtime_args! {
+            NONE_LIST => vec![Key::Account(*ACCOUNT_1_ADDR), Key::Account(*ACCOUNT_2_ADDR)],
+        }))
+        .expect_success()
+        .commit();
+    assert_eq!(
+        cep18_check_admins(&mut builder, 0, 10),
+        vec![Key::Account(*DEFAULT_ACCOUNT_ADDR)]
+    );
+    assert!(cep18_check_minters(&mut builder, 0, 10).is_empty());
+}
+
+#[test]
+fn should_index_legacy_badge_holders() {
+    let (mut builder, _) = setup_1_1_0_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => 1u8,
+        ADMIN_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+        MINTER_LIST => vec![Key::Account(*ACCOUNT_2_ADDR)],
+    });
+    let legacy_holders = vec![
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        Key::Account(*ACCOUNT_1_ADDR),
+        Key::Account(*ACCOUNT_2_ADDR),
+    ];
+
+    let upgrade_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CEP18_CONTRACT_WASM,
+        runtime_args! {
+            ARG_NAME => TOKEN_NAME,
+        },
+    )
+    .build();
+    builder.exec(upgrade_request).expect_success().commit();
+    let cep18_token = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(CEP18_TOKEN_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .expect("should have upgraded contract hash");
+
+    // The legacy badges still grant their roles, and indexing lists their holders. Indexing the
+    // same accounts again lists them once.
+    assert_eq!(
+        cep18_check_badge(&mut builder, Some(Key::Account(*ACCOUNT_1_ADDR))),
+        ROLE_ADMIN
+    );
+    for _ in 0..2 {
+        let index_roles_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            cep18_token,
+            METHOD_INDEX_ROLES,
+            runtime_args! {
+                ARG_ACCOUNTS => legacy_holders.clone(),
+            },
+        )
+        .build();
+        builder.exec(index_roles_request).expect_success().commit();
+
+        assert_eq!(
+            cep18_check_admins(&mut builder, 0, 10),
+            vec![
+                Key::Account(*DEFAULT_ACCOUNT_ADDR),
+                Key::Account(*ACCOUNT_1_ADDR)
+            ]
+        );
+        assert_eq!(
+            cep18_check_minters(&mut builder, 0, 10),
+            vec![Key::Account(*ACCOUNT_2_ADDR)]
+        );
+    }
+
+    let change_security_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        CHANGE_SECURITY,
+        runtime_args! {
+            NONE_LIST => vec![Key::Account(*ACCOUNT_2_ADDR)],
+        },
+    )
+    .build();
+    builder
+        .exec(change_security_request)
+        .expect_success()
+        .commit();
+
+    assert!(cep18_check_minters(&mut builder, 0, 10).is_empty());
+}
+
+#[test]
+fn should_return_strongest_badge() {
+    let (mut builder, _) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        MINTER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR), Key::Account(*ACCOUNT_2_ADDR)],
+        ADMIN_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+    });
+
+    assert_eq!(
+        cep18_check_badge(&mut builder, Some(Key::Account(*ACCOUNT_1_ADDR))),
+        ROLE_ADMIN
+    );
+    assert_eq!(
+        cep18_check_badge(&mut builder, Some(Key::Account(*ACCOUNT_2_ADDR))),
+        ROLE_MINTER
+    );
+    // Without an account the badge of the caller, here the test contract, is returned.
+    assert_eq!(cep18_check_badge(&mut builder, None), ROLE_NONE);
+}
@@ -0,0 +1,267 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ADMIN_COUNT_KEY, ADMIN_LIST, ARG_ACCOUNT, ARG_AMOUNT, ARG_DECIMALS,
+        ARG_NAME, ARG_OWNER, ARG_ROLE, ARG_SYMBOL, ARG_TOTAL_SUPPLY, CHANGE_SECURITY,
+        ENABLE_MINT_BURN, ERROR_INSUFFICIENT_RIGHTS, ERROR_INVALID_ROLE, ERROR_LAST_ADMIN,
+        METHOD_GRANT_ROLE, METHOD_MINT, METHOD_RENOUNCE_ADMIN, METHOD_REVOKE_ROLE, MINTER_LIST,
+        NONE_LIST, PAUSER_LIST, ROLE_ADMIN, ROLE_MINTER, ROLE_NONE, ROLE_PAUSER, TOKEN_DECIMALS,
+        TOKEN_NAME, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+    },
+    installer_request_builders::{cep18_check_has_role, setup_with_args, TestContext},
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+#[test]
+fn should_hold_several_roles_from_install() {
+    let (mut builder, _) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        MINTER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+        PAUSER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+    });
+    let account = Key::Account(*ACCOUNT_1_ADDR);
+
+    assert!(cep18_check_has_role(&mut builder, account, ROLE_MINTER));
+    assert!(cep18_check_has_role(&mut builder, account, ROLE_PAUSER));
+    assert!(!cep18_check_has_role(&mut builder, account, ROLE_ADMIN));
+    assert!(cep18_check_has_role(
+        &mut builder,
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        ROLE_ADMIN
+    ));
+}
+
+#[test]
+fn should_grant_and_revoke_roles_independently() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        MINTER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+    });
+    let account = Key::Account(*ACCOUNT_1_ADDR);
+
+    let grant_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_GRANT_ROLE,
+        runtime_args! {
+            ARG_ACCOUNT => account,
+            ARG_ROLE => ROLE_PAUSER,
+        },
+    )
+    .build();
+    builder.exec(grant_request).expect_success().commit();
+
+    assert!(cep18_check_has_role(&mut builder, account, ROLE_MINTER));
+    assert!(cep18_check_has_role(&mut builder, account, ROLE_PAUSER));
+
+    let revoke_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_REVOKE_ROLE,
+        runtime_args! {
+            ARG_ACCOUNT => account,
+            ARG_ROLE => ROLE_MINTER,
+        },
+    )
+    .build();
+    builder.exec(revoke_request).expect_success().commit();
+
+    assert!(!cep18_check_has_role(&mut builder, account, ROLE_MINTER));
+    assert!(cep18_check_has_role(&mut builder, account, ROLE_PAUSER));
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => account,
+            ARG_AMOUNT => U256::one(),
+        },
+    )
+    .build();
+    builder.exec(mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_RIGHTS),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_only_let_admins_grant_valid_roles() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+    });
+
+    let grant_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_GRANT_ROLE,
+        runtime_args! {
+            ARG_ACCOUNT => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_ROLE => ROLE_ADMIN,
+        },
+    )
+    .build();
+    builder.exec(grant_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_RIGHTS),
+        "{:?}",
+        error
+    );
+
+    let grant_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_GRANT_ROLE,
+        runtime_args! {
+            ARG_ACCOUNT => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_ROLE => ROLE_NONE,
+        },
+    )
+    .build();
+    builder.exec(grant_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INVALID_ROLE),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_remove_last_admin_unless_renounced() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+    });
+    let admin = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let admin_count: u32 = builder.get_value(cep18_token, ADMIN_COUNT_KEY);
+    assert_eq!(admin_count, 1);
+
+    let revoke_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_REVOKE_ROLE,
+        runtime_args! {
+            ARG_ACCOUNT => admin,
+            ARG_ROLE => ROLE_ADMIN,
+        },
+    )
+    .build();
+    builder.exec(revoke_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_LAST_ADMIN),
+        "{:?}",
+        error
+    );
+
+    let renounce_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_RENOUNCE_ADMIN,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(renounce_request).expect_success().commit();
+
+    let admin_count: u32 = builder.get_value(cep18_token, ADMIN_COUNT_KEY);
+    assert_eq!(admin_count, 0);
+    assert!(!cep18_check_has_role(&mut builder, admin, ROLE_ADMIN));
+
+    let grant_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_GRANT_ROLE,
+        runtime_args! {
+            ARG_ACCOUNT => admin,
+            ARG_ROLE => ROLE_ADMIN,
+        },
+    )
+    .build();
+    builder.exec(grant_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_RIGHTS),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_count_admins_across_role_changes() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        ADMIN_LIST => vec![Key::Account(*ACCOUNT_1_ADDR), Key::Account(*DEFAULT_ACCOUNT_ADDR)],
+    });
+    let admin_count: u32 = builder.get_value(cep18_token, ADMIN_COUNT_KEY);
+    assert_eq!(admin_count, 2);
+
+    let change_security_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        CHANGE_SECURITY,
+        runtime_args! {
+            NONE_LIST => vec![Key::Account(*DEFAULT_ACCOUNT_ADDR)],
+        },
+    )
+    .build();
+    builder
+        .exec(change_security_request)
+        .expect_success()
+        .commit();
+
+    let admin_count: u32 = builder.get_value(cep18_token, ADMIN_COUNT_KEY);
+    assert_eq!(admin_count, 1);
+
+    let revoke_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_REVOKE_ROLE,
+        runtime_args! {
+            ARG_ACCOUNT => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_ROLE => ROLE_ADMIN,
+        },
+    )
+    .build();
+    builder.exec(revoke_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_LAST_ADMIN),
+        "{:?}",
+        error
+    );
+}
@@ -0,0 +1,304 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{bytesrepr::Bytes, runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ALLOWANCE_AMOUNT_1, ARG_AMOUNT, ARG_DECIMALS, ARG_NAME, ARG_OWNER,
+        ARG_RECIPIENT, ARG_SYMBOL, ARG_TOKEN_CONTRACT, ARG_TOTAL_SUPPLY, CEP18_CONTRACT_WASM,
+        ENABLE_PAUSE, ERROR_INVALID_RECEIVER, ERROR_UNSAFE_RECIPIENT, METHOD_PAUSE,
+        METHOD_REGISTER_AS_RECEIVER, METHOD_REGISTER_RECEIVER, METHOD_SAFE_TRANSFER,
+        METHOD_SAFE_TRANSFER_FROM, METHOD_UNREGISTER_AS_RECEIVER, TOKEN_DECIMALS, TOKEN_NAME,
+        TOKEN_OWNER_ADDRESS_1, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+    },
+    installer_request_builders::{
+        cep18_check_balance_of, get_test_result, make_cep18_approve_request, setup,
+        setup_with_args, TestContext,
+    },
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+#[test]
+fn should_safe_transfer_to_accounts_and_registered_receivers() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_token,
+            cep18_test_contract_package,
+        },
+    ) = setup();
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let receiver = Key::from(cep18_test_contract_package);
+    let amount = U256::from(100);
+
+    let register_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        METHOD_REGISTER_AS_RECEIVER,
+        runtime_args! {
+            ARG_TOKEN_CONTRACT => Key::from(cep18_token),
+        },
+    )
+    .build();
+    builder.exec(register_request).expect_success().commit();
+
+    for recipient in [TOKEN_OWNER_ADDRESS_1, receiver] {
+        let safe_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            cep18_token,
+            METHOD_SAFE_TRANSFER,
+            runtime_args! {
+                ARG_RECIPIENT => recipient,
+                ARG_AMOUNT => amount,
+            },
+        )
+        .build();
+        builder
+            .exec(safe_transfer_request)
+            .expect_success()
+            .commit();
+    }
+
+    let approve_request = make_cep18_approve_request(
+        owner,
+        &cep18_token,
+        Key::Account(*ACCOUNT_1_ADDR),
+        ALLOWANCE_AMOUNT_1.into(),
+    );
+    builder.exec(approve_request).expect_success().commit();
+
+    let safe_transfer_from_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_SAFE_TRANSFER_FROM,
+        runtime_args! {
+            ARG_OWNER => owner,
+            ARG_RECIPIENT => receiver,
+            ARG_AMOUNT => amount,
+        },
+    )
+    .build();
+    builder
+        .exec(safe_transfer_from_request)
+        .expect_success()
+        .commit();
+
+    // The hook of the receiver was called with the owner of the tokens as the sender.
+    let received: (Key, U256, Bytes) = get_test_result(&mut builder, cep18_test_contract_package);
+    assert_eq!(received, (owner, amount, Bytes::new()));
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, TOKEN_OWNER_ADDRESS_1),
+        amount
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, receiver),
+        amount * 2
+    );
+}
+
+#[test]
+fn should_not_safe_transfer_to_unsafe_recipients() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_token,
+            cep18_test_contract_package,
+        },
+    ) = setup();
+    let token_package = Key::from(
+        builder
+            .get_contract(cep18_token)
+            .expect("should have contract")
+            .contract_package_hash(),
+    );
+    // Another token has no `on_cep18_received` hook and cannot move tokens it holds.
+    let other_token_install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CEP18_CONTRACT_WASM,
+        runtime_args! {
+            ARG_NAME => "OtherToken",
+            ARG_SYMBOL => TOKEN_SYMBOL,
+            ARG_DECIMALS => TOKEN_DECIMALS,
+            ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        },
+    )
+    .build();
+    builder
+        .exec(other_token_install_request)
+        .expect_success()
+        .commit();
+    let other_token_package = *builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get("cep18_contract_package_OtherToken")
+        .expect("should have other token package");
+    // The test contract implements the hook, but never registered as a receiver.
+    let unregistered_receiver = Key::from(cep18_test_contract_package);
+    let dictionary = Key::Dictionary([42; 32]);
+
+    let approve_request = make_cep18_approve_request(
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        &cep18_token,
+        Key::Account(*ACCOUNT_1_ADDR),
+        ALLOWANCE_AMOUNT_1.into(),
+    );
+    builder.exec(approve_request).expect_success().commit();
+
+    for recipient in [
+        other_token_package,
+        unregistered_receiver,
+        token_package,
+        dictionary,
+    ] {
+        let safe_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            cep18_token,
+            METHOD_SAFE_TRANSFER,
+            runtime_args! {
+                ARG_RECIPIENT => recipient,
+                ARG_AMOUNT => U256::from(100),
+            },
+        )
+        .build();
+        let safe_transfer_from_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *ACCOUNT_1_ADDR,
+            cep18_token,
+            METHOD_SAFE_TRANSFER_FROM,
+            runtime_args! {
+                ARG_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+                ARG_RECIPIENT => recipient,
+                ARG_AMOUNT => U256::from(100),
+            },
+        )
+        .build();
+        for request in [safe_transfer_request, safe_transfer_from_request] {
+            builder.exec(request).commit();
+
+            let error = builder.get_error().expect("should have error");
+            assert!(
+                matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_UNSAFE_RECIPIENT),
+                "{:?}",
+                error
+            );
+        }
+        assert_eq!(
+            cep18_check_balance_of(&mut builder, &cep18_token, recipient),
+            U256::zero()
+        );
+    }
+}
+
+#[test]
+fn should_not_register_accounts_as_receivers() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    let register_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_REGISTER_RECEIVER,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(register_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INVALID_RECEIVER),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_safe_transfer_to_unregistered_receivers() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_token,
+            cep18_test_contract_package,
+        },
+    ) = setup();
+    let receiver = Key::from(cep18_test_contract_package);
+
+    for method in [METHOD_REGISTER_AS_RECEIVER, METHOD_UNREGISTER_AS_RECEIVER] {
+        let request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            cep18_test_contract_package,
+            None,
+            method,
+            runtime_args! {
+                ARG_TOKEN_CONTRACT => Key::from(cep18_token),
+            },
+        )
+        .build();
+        builder.exec(request).expect_success().commit();
+    }
+
+    let safe_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_SAFE_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENT => receiver,
+            ARG_AMOUNT => U256::from(100),
+        },
+    )
+    .build();
+    builder.exec(safe_transfer_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_UNSAFE_RECIPIENT),
+        "{:?}",
+        error
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, receiver),
+        U256::zero()
+    );
+}
+
+#[test]
+fn should_register_receivers_while_paused() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_token,
+            cep18_test_contract_package,
+        },
+    ) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_PAUSE => 1u8,
+    });
+
+    let pause_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_PAUSE,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(pause_request).expect_success().commit();
+
+    for method in [METHOD_REGISTER_AS_RECEIVER, METHOD_UNREGISTER_AS_RECEIVER] {
+        let request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            cep18_test_contract_package,
+            None,
+            method,
+            runtime_args! {
+                ARG_TOKEN_CONTRACT => Key::from(cep18_token),
+            },
+        )
+        .build();
+        builder.exec(request).expect_success().commit();
+    }
+}
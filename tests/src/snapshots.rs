@@ -0,0 +1,156 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ARG_ADDRESS, ARG_AMOUNT, ARG_DECIMALS, ARG_NAME, ARG_OWNER,
+        ARG_SNAPSHOT_ID, ARG_SYMBOL, ARG_TOKEN_CONTRACT, ARG_TOTAL_SUPPLY,
+        CHECK_BALANCE_OF_AT_ENTRYPOINT, ENABLE_MINT_BURN, ERROR_INSUFFICIENT_RIGHTS,
+        ERROR_INVALID_SNAPSHOT_ID, METHOD_MINT, METHOD_SNAPSHOT, TOKEN_DECIMALS, TOKEN_NAME,
+        TOKEN_OWNER_ADDRESS_1, TOKEN_OWNER_ADDRESS_2, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+    },
+    installer_request_builders::{
+        cep18_check_balance_of, cep18_check_balance_of_at, cep18_check_total_supply_at,
+        make_cep18_transfer_request, setup, setup_with_args, TestContext,
+    },
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+#[test]
+fn should_record_balances_and_total_supply_at_snapshots() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+    });
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let snapshot_request = || {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            cep18_token,
+            METHOD_SNAPSHOT,
+            runtime_args! {},
+        )
+        .build()
+    };
+
+    builder.exec(snapshot_request()).expect_success().commit();
+
+    let transfer_request = make_cep18_transfer_request(
+        owner,
+        &cep18_token,
+        TOKEN_OWNER_ADDRESS_1,
+        U256::from(1_000),
+    );
+    builder.exec(transfer_request).expect_success().commit();
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => U256::from(500),
+        },
+    )
+    .build();
+    builder.exec(mint_request).expect_success().commit();
+
+    builder.exec(snapshot_request()).expect_success().commit();
+
+    let transfer_request =
+        make_cep18_transfer_request(owner, &cep18_token, TOKEN_OWNER_ADDRESS_1, U256::from(100));
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        builder.get_value::<u64>(cep18_token, "current_snapshot_id"),
+        2
+    );
+    assert_eq!(
+        cep18_check_balance_of_at(&mut builder, &cep18_token, TOKEN_OWNER_ADDRESS_1, 1),
+        U256::zero()
+    );
+    assert_eq!(
+        cep18_check_balance_of_at(&mut builder, &cep18_token, TOKEN_OWNER_ADDRESS_1, 2),
+        U256::from(1_500)
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, TOKEN_OWNER_ADDRESS_1),
+        U256::from(1_600)
+    );
+    assert_eq!(
+        cep18_check_balance_of_at(&mut builder, &cep18_token, owner, 1),
+        U256::from(TOKEN_TOTAL_SUPPLY)
+    );
+    assert_eq!(
+        cep18_check_balance_of_at(&mut builder, &cep18_token, owner, 2),
+        U256::from(TOKEN_TOTAL_SUPPLY) - U256::from(1_000)
+    );
+    assert_eq!(
+        cep18_check_balance_of_at(&mut builder, &cep18_token, TOKEN_OWNER_ADDRESS_2, 2),
+        U256::zero()
+    );
+    assert_eq!(
+        cep18_check_total_supply_at(&mut builder, &cep18_token, 1),
+        U256::from(TOKEN_TOTAL_SUPPLY)
+    );
+    assert_eq!(
+        cep18_check_total_supply_at(&mut builder, &cep18_token, 2),
+        U256::from(TOKEN_TOTAL_SUPPLY) + U256::from(500)
+    );
+}
+
+#[test]
+fn should_only_snapshot_by_admin_and_query_taken_snapshots() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_token,
+            cep18_test_contract_package,
+        },
+    ) = setup();
+
+    let snapshot_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_SNAPSHOT,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(snapshot_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_RIGHTS),
+        "{:?}",
+        error
+    );
+
+    for snapshot_id in [0u64, 1] {
+        let balance_of_at_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            cep18_test_contract_package,
+            None,
+            CHECK_BALANCE_OF_AT_ENTRYPOINT,
+            runtime_args! {
+                ARG_TOKEN_CONTRACT => Key::from(cep18_token),
+                ARG_ADDRESS => TOKEN_OWNER_ADDRESS_1,
+                ARG_SNAPSHOT_ID => snapshot_id,
+            },
+        )
+        .build();
+        builder.exec(balance_of_at_request).commit();
+
+        let error = builder.get_error().expect("should have error");
+        assert!(
+            matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INVALID_SNAPSHOT_ID),
+            "{:?}",
+            error
+        );
+    }
+}
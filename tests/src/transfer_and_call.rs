@@ -0,0 +1,102 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{bytesrepr::Bytes, runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ARG_AMOUNT, ARG_DATA, ARG_RECIPIENT, ERROR_INVALID_RECEIVER, ERROR_TRANSFER_REJECTED,
+        METHOD_TRANSFER_AND_CALL, TOKEN_OWNER_ADDRESS_1, TOKEN_TOTAL_SUPPLY,
+    },
+    installer_request_builders::{cep18_check_balance_of, get_test_result, setup, TestContext},
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+/// Error the test contract's receiver hook reverts with when sent `b"revert"` as data.
+const RECEIVER_REVERT_ERROR: u16 = 1;
+
+#[test]
+fn should_transfer_and_call_receiver_hook() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_token,
+            cep18_test_contract_package,
+        },
+    ) = setup();
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let receiver = Key::from(cep18_test_contract_package);
+    let amount = U256::from(100);
+    let data = Bytes::from(b"deposit:42".to_vec());
+
+    let transfer_and_call_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_TRANSFER_AND_CALL,
+        runtime_args! {
+            ARG_RECIPIENT => receiver,
+            ARG_AMOUNT => amount,
+            ARG_DATA => data.clone(),
+        },
+    )
+    .build();
+    builder
+        .exec(transfer_and_call_request)
+        .expect_success()
+        .commit();
+
+    let received: (Key, U256, Bytes) = get_test_result(&mut builder, cep18_test_contract_package);
+    assert_eq!(received, (sender, amount, data));
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, receiver),
+        amount
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, sender),
+        U256::from(TOKEN_TOTAL_SUPPLY) - amount
+    );
+}
+
+#[test]
+fn should_revert_transfer_and_call_unless_received() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_token,
+            cep18_test_contract_package,
+        },
+    ) = setup();
+    let receiver = Key::from(cep18_test_contract_package);
+
+    let expected_errors = [
+        (receiver, b"reject".to_vec(), ERROR_TRANSFER_REJECTED),
+        (receiver, b"revert".to_vec(), RECEIVER_REVERT_ERROR),
+        (TOKEN_OWNER_ADDRESS_1, Vec::new(), ERROR_INVALID_RECEIVER),
+    ];
+    for (recipient, data, expected_error) in expected_errors {
+        let transfer_and_call_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            cep18_token,
+            METHOD_TRANSFER_AND_CALL,
+            runtime_args! {
+                ARG_RECIPIENT => recipient,
+                ARG_AMOUNT => U256::from(100),
+                ARG_DATA => Bytes::from(data),
+            },
+        )
+        .build();
+        builder.exec(transfer_and_call_request).commit();
+
+        let error = builder.get_error().expect("should have error");
+        assert!(
+            matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == expected_error),
+            "{:?}",
+            error
+        );
+        assert_eq!(
+            cep18_check_balance_of(&mut builder, &cep18_token, recipient),
+            U256::zero()
+        );
+    }
+}
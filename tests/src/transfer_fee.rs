@@ -0,0 +1,302 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_ACCOUNT, ARG_AMOUNT, ARG_DECIMALS, ARG_FEE_RATE,
+        ARG_MAX_FEE_RATE, ARG_NAME, ARG_OWNER, ARG_RECIPIENT, ARG_SYMBOL, ARG_TOTAL_SUPPLY,
+        ARG_TREASURY, ENABLE_FREEZE, ERROR_INSUFFICIENT_RIGHTS, ERROR_INVALID_FEE_RATE,
+        ERROR_TRANSFER_FEE_DISABLED, FEE_EXEMPT_LIST, FEE_NON_EXEMPT_LIST,
+        METHOD_CHANGE_FEE_EXEMPTION, METHOD_FREEZE_ACCOUNT, METHOD_SET_FEE_RATE,
+        METHOD_SET_TREASURY, METHOD_TRANSFER_FROM, TOKEN_DECIMALS, TOKEN_NAME,
+        TOKEN_OWNER_ADDRESS_1, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY, TRANSFER_FEE,
+    },
+    installer_request_builders::{
+        cep18_check_balance_of, make_cep18_approve_request, make_cep18_transfer_request,
+        setup_with_args, TestContext,
+    },
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+#[test]
+fn should_route_transfer_fee_to_treasury() {
+    let treasury = Key::Account(*ACCOUNT_2_ADDR);
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        TRANSFER_FEE => 1u8,
+        ARG_FEE_RATE => 100u32,
+        ARG_MAX_FEE_RATE => 500u32,
+        ARG_TREASURY => treasury,
+    });
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let spender = Key::Account(*ACCOUNT_1_ADDR);
+
+    let transfer_request = make_cep18_transfer_request(
+        owner,
+        &cep18_token,
+        TOKEN_OWNER_ADDRESS_1,
+        U256::from(1_000),
+    );
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, TOKEN_OWNER_ADDRESS_1),
+        U256::from(990)
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, treasury),
+        U256::from(10)
+    );
+
+    let approve_request =
+        make_cep18_approve_request(owner, &cep18_token, spender, U256::from(1_000));
+    builder.exec(approve_request).expect_success().commit();
+
+    let transfer_from_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_TRANSFER_FROM,
+        runtime_args! {
+            ARG_OWNER => owner,
+            ARG_RECIPIENT => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => U256::from(1_000),
+        },
+    )
+    .build();
+    builder
+        .exec(transfer_from_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, TOKEN_OWNER_ADDRESS_1),
+        U256::from(1_980)
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, treasury),
+        U256::from(20)
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, owner),
+        U256::from(TOKEN_TOTAL_SUPPLY) - U256::from(2_000)
+    );
+}
+
+#[test]
+fn should_route_transfer_fee_to_frozen_treasury() {
+    let treasury = Key::Account(*ACCOUNT_2_ADDR);
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_FREEZE => 1u8,
+        TRANSFER_FEE => 1u8,
+        ARG_FEE_RATE => 100u32,
+        ARG_MAX_FEE_RATE => 500u32,
+        ARG_TREASURY => treasury,
+    });
+
+    let freeze_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_FREEZE_ACCOUNT,
+        runtime_args! {
+            ARG_ACCOUNT => treasury,
+        },
+    )
+    .build();
+    builder.exec(freeze_request).expect_success().commit();
+
+    let transfer_request = make_cep18_transfer_request(
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        &cep18_token,
+        TOKEN_OWNER_ADDRESS_1,
+        U256::from(1_000),
+    );
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, TOKEN_OWNER_ADDRESS_1),
+        U256::from(990)
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, treasury),
+        U256::from(10)
+    );
+}
+
+#[test]
+fn should_not_charge_fee_to_exempt_accounts() {
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let treasury = Key::Account(*ACCOUNT_2_ADDR);
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        TRANSFER_FEE => 1u8,
+        ARG_FEE_RATE => 100u32,
+        ARG_MAX_FEE_RATE => 100u32,
+        ARG_TREASURY => treasury,
+        FEE_EXEMPT_LIST => vec![owner],
+    });
+
+    let transfer_request = make_cep18_transfer_request(
+        owner,
+        &cep18_token,
+        TOKEN_OWNER_ADDRESS_1,
+        U256::from(1_000),
+    );
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, TOKEN_OWNER_ADDRESS_1),
+        U256::from(1_000)
+    );
+
+    let change_fee_exemption_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_CHANGE_FEE_EXEMPTION,
+        runtime_args! {
+            FEE_NON_EXEMPT_LIST => vec![owner],
+        },
+    )
+    .build();
+    builder
+        .exec(change_fee_exemption_request)
+        .expect_success()
+        .commit();
+
+    let transfer_request = make_cep18_transfer_request(
+        owner,
+        &cep18_token,
+        TOKEN_OWNER_ADDRESS_1,
+        U256::from(1_000),
+    );
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, TOKEN_OWNER_ADDRESS_1),
+        U256::from(1_990)
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, treasury),
+        U256::from(10)
+    );
+}
+
+#[test]
+fn should_bound_fee_rate_and_change_treasury() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        TRANSFER_FEE => 1u8,
+        ARG_MAX_FEE_RATE => 200u32,
+    });
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let treasury = Key::Account(*ACCOUNT_2_ADDR);
+
+    let set_fee_rate_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_SET_FEE_RATE,
+        runtime_args! { ARG_FEE_RATE => 201u32 },
+    )
+    .build();
+    builder.exec(set_fee_rate_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INVALID_FEE_RATE),
+        "{:?}",
+        error
+    );
+
+    let set_treasury_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_SET_TREASURY,
+        runtime_args! { ARG_TREASURY => Key::Account(*ACCOUNT_1_ADDR) },
+    )
+    .build();
+    builder.exec(set_treasury_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_RIGHTS),
+        "{:?}",
+        error
+    );
+
+    for request in [
+        ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            cep18_token,
+            METHOD_SET_FEE_RATE,
+            runtime_args! { ARG_FEE_RATE => 200u32 },
+        )
+        .build(),
+        ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            cep18_token,
+            METHOD_SET_TREASURY,
+            runtime_args! { ARG_TREASURY => treasury },
+        )
+        .build(),
+    ] {
+        builder.exec(request).expect_success().commit();
+    }
+
+    let transfer_request = make_cep18_transfer_request(
+        owner,
+        &cep18_token,
+        TOKEN_OWNER_ADDRESS_1,
+        U256::from(1_000),
+    );
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, TOKEN_OWNER_ADDRESS_1),
+        U256::from(980)
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, treasury),
+        U256::from(20)
+    );
+}
+
+#[test]
+fn should_not_set_fee_rate_when_transfer_fee_disabled() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+    });
+
+    let set_fee_rate_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_SET_FEE_RATE,
+        runtime_args! { ARG_FEE_RATE => 0u32 },
+    )
+    .build();
+    builder.exec(set_fee_rate_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_TRANSFER_FEE_DISABLED),
+        "{:?}",
+        error
+    );
+}
@@ -0,0 +1,185 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{
+    bytesrepr::{Bytes, FromBytes},
+    runtime_args, ContractHash, Key, RuntimeArgs, U256,
+};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ALLOWANCE_AMOUNT_1, ARG_AMOUNT, ARG_DECIMALS, ARG_MEMO, ARG_NAME,
+        ARG_OWNER, ARG_RECIPIENT, ARG_SPENDER, ARG_SYMBOL, ARG_TOTAL_SUPPLY, EVENTS_DICT_KEY,
+        EVENTS_LENGTH_KEY, EVENTS_MODE, METHOD_APPROVE, METHOD_TRANSFER_FROM_WITH_MEMO,
+        METHOD_TRANSFER_WITH_MEMO, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+        TRANSFER_AMOUNT_1,
+    },
+    installer_request_builders::{
+        cep18_check_allowance_of, cep18_check_balance_of, setup_with_args, TestContext,
+    },
+};
+
+const MEMO: u64 = 42;
+
+fn setup_with_events() -> (InMemoryWasmTestBuilder, TestContext) {
+    setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        EVENTS_MODE => 1u8,
+    })
+}
+
+/// Reads the last event emitted by the token, decoded as a `TransferWithMemo` event.
+fn read_last_transfer_with_memo(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_token: ContractHash,
+) -> (String, Key, Key, U256, u64) {
+    let events_length: u32 = builder.get_value(cep18_token, EVENTS_LENGTH_KEY);
+    let events_uref = *builder
+        .get_contract(cep18_token)
+        .expect("should have contract")
+        .named_keys()
+        .get(EVENTS_DICT_KEY)
+        .expect("should have events")
+        .as_uref()
+        .expect("should be a uref");
+    let event: Bytes = builder
+        .query_dictionary_item(None, events_uref, &(events_length - 1).to_string())
+        .expect("should have event")
+        .as_cl_value()
+        .cloned()
+        .expect("should be a cl value")
+        .into_t()
+        .expect("should be bytes");
+    let (event, remainder) =
+        <(String, Key, Key, U256, u64)>::from_bytes(&event).expect("should decode event");
+    assert!(remainder.is_empty());
+    event
+}
+
+#[test]
+fn should_transfer_with_memo() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_events();
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_1_ADDR);
+    let events_before: u32 = builder.get_value(cep18_token, EVENTS_LENGTH_KEY);
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_TRANSFER_WITH_MEMO,
+        runtime_args! {
+            ARG_RECIPIENT => recipient,
+            ARG_AMOUNT => U256::from(TRANSFER_AMOUNT_1),
+            ARG_MEMO => MEMO,
+        },
+    )
+    .build();
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, recipient),
+        U256::from(TRANSFER_AMOUNT_1)
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, sender),
+        U256::from(TOKEN_TOTAL_SUPPLY - TRANSFER_AMOUNT_1)
+    );
+    let events_after: u32 = builder.get_value(cep18_token, EVENTS_LENGTH_KEY);
+    assert_eq!(events_after - events_before, 2);
+    assert_eq!(
+        read_last_transfer_with_memo(&mut builder, cep18_token),
+        (
+            "event_TransferWithMemo".to_string(),
+            sender,
+            recipient,
+            U256::from(TRANSFER_AMOUNT_1),
+            MEMO
+        )
+    );
+}
+
+#[test]
+fn should_transfer_from_with_memo() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_events();
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let spender = Key::Account(*ACCOUNT_1_ADDR);
+    let recipient = Key::Hash([42; 32]);
+
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_APPROVE,
+        runtime_args! {
+            ARG_SPENDER => spender,
+            ARG_AMOUNT => U256::from(ALLOWANCE_AMOUNT_1),
+        },
+    )
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+
+    let transfer_from_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_TRANSFER_FROM_WITH_MEMO,
+        runtime_args! {
+            ARG_OWNER => owner,
+            ARG_RECIPIENT => recipient,
+            ARG_AMOUNT => U256::from(TRANSFER_AMOUNT_1),
+            ARG_MEMO => MEMO,
+        },
+    )
+    .build();
+    builder
+        .exec(transfer_from_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, recipient),
+        U256::from(TRANSFER_AMOUNT_1)
+    );
+    assert_eq!(
+        cep18_check_allowance_of(&mut builder, owner, spender),
+        U256::from(ALLOWANCE_AMOUNT_1 - TRANSFER_AMOUNT_1)
+    );
+    assert_eq!(
+        read_last_transfer_with_memo(&mut builder, cep18_token),
+        (
+            "event_TransferWithMemo".to_string(),
+            owner,
+            recipient,
+            U256::from(TRANSFER_AMOUNT_1),
+            MEMO
+        )
+    );
+}
+
+#[test]
+fn should_skip_zero_amount_transfer_from_with_memo() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_events();
+    let events_before: u32 = builder.get_value(cep18_token, EVENTS_LENGTH_KEY);
+
+    // Like `transfer_from`, a zero amount needs no allowance and moves nothing.
+    let transfer_from_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_TRANSFER_FROM_WITH_MEMO,
+        runtime_args! {
+            ARG_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_RECIPIENT => Key::Hash([42; 32]),
+            ARG_AMOUNT => U256::zero(),
+            ARG_MEMO => MEMO,
+        },
+    )
+    .build();
+    builder
+        .exec(transfer_from_request)
+        .expect_success()
+        .commit();
+
+    let events_after: u32 = builder.get_value(cep18_token, EVENTS_LENGTH_KEY);
+    assert_eq!(events_after, events_before);
+}
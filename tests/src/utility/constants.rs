@@ -3,6 +3,9 @@ use once_cell::sync::Lazy;
 
 pub const CEP18_CONTRACT_WASM: &str = "cep18.wasm";
 pub const CEP18_TEST_CONTRACT_WASM: &str = "cep18_test_contract.wasm";
+/// Release 1.1.0 of the token, built and stripped like `cep18.wasm`, to test upgrades from it.
+pub const CEP18_1_1_0_CONTRACT_WASM: &str =
+    concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/cep18_1.1.0.wasm");
 pub const NAME_KEY: &str = "name";
 pub const SYMBOL_KEY: &str = "symbol";
 pub const CEP18_TOKEN_CONTRACT_KEY: &str = "cep18_contract_hash_CasperTest";
@@ -81,3 +84,141 @@ pub const ADMIN_LIST: &str = "admin_list";
 pub const MINTER_LIST: &str = "minter_list";
 pub const NONE_LIST: &str = "none_list";
 pub const CHANGE_SECURITY: &str = "change_security";
+pub const ENABLE_PAUSE: &str = "enable_pause";
+pub const PAUSER_LIST: &str = "pauser_list";
+pub const METHOD_PAUSE: &str = "pause";
+pub const METHOD_UNPAUSE: &str = "unpause";
+pub const ERROR_INSUFFICIENT_RIGHTS: u16 = 60010;
+pub const ERROR_CONTRACT_PAUSED: u16 = 60020;
+pub const ERROR_PAUSE_DISABLED: u16 = 60021;
+pub const ENABLE_FREEZE: &str = "enable_freeze";
+pub const METHOD_FREEZE_ACCOUNT: &str = "freeze_account";
+pub const METHOD_UNFREEZE_ACCOUNT: &str = "unfreeze_account";
+pub const ARG_ACCOUNT: &str = "account";
+pub const ERROR_ACCOUNT_FROZEN: u16 = 60024;
+pub const ERROR_FREEZE_DISABLED: u16 = 60025;
+pub const TRANSFER_RESTRICTION: &str = "transfer_restriction";
+pub const ALLOWLISTER_LIST: &str = "allowlister_list";
+pub const ALLOW_LIST: &str = "allow_list";
+pub const DISALLOW_LIST: &str = "disallow_list";
+pub const METHOD_CHANGE_ALLOWLIST: &str = "change_allowlist";
+pub const ERROR_SENDER_NOT_ALLOWLISTED: u16 = 60027;
+pub const ERROR_RECIPIENT_NOT_ALLOWLISTED: u16 = 60028;
+pub const ERROR_ALLOWLIST_DISABLED: u16 = 60029;
+pub const MAX_SUPPLY_KEY: &str = "max_supply";
+pub const ARG_MAX_SUPPLY: &str = "max_supply";
+pub const METHOD_LOWER_MAX_SUPPLY: &str = "lower_max_supply";
+pub const ERROR_EXCEEDS_MAX_SUPPLY: u16 = 60034;
+pub const ERROR_INVALID_MAX_SUPPLY: u16 = 60035;
+pub const ARG_EXPIRES_AT: &str = "expires_at";
+pub const CHECK_ALLOWANCE_WITH_EXPIRY_OF_ENTRYPOINT: &str = "check_allowance_with_expiry_of";
+pub const METHOD_PERMIT: &str = "permit";
+pub const ARG_DEADLINE: &str = "deadline";
+pub const ARG_NONCE: &str = "nonce";
+pub const ARG_SIGNATURE: &str = "signature";
+pub const PERMIT_MESSAGE_PREFIX: &str = "cep18_permit";
+pub const ERROR_PERMIT_EXPIRED: u16 = 60037;
+pub const ERROR_INVALID_NONCE: u16 = 60038;
+pub const ERROR_INVALID_SIGNATURE: u16 = 60039;
+pub const METHOD_BATCH_TRANSFER: &str = "batch_transfer";
+pub const ARG_TRANSFERS: &str = "transfers";
+pub const EVENTS_MODE: &str = "events_mode";
+pub const BATCH_TRANSFER_EVENTS: &str = "batch_transfer_events";
+pub const EVENTS_LENGTH_KEY: &str = "__events_length";
+pub const METHOD_BATCH_MINT: &str = "batch_mint";
+pub const ARG_MINTS: &str = "mints";
+pub const ARG_INITIAL_BALANCES: &str = "initial_balances";
+pub const METHOD_BURN_FROM: &str = "burn_from";
+pub const BURNER_LIST: &str = "burner_list";
+pub const MINT_AND_BURN_LIST: &str = "mint_and_burn_list";
+pub const ERROR_INVALID_BURN_TARGET: u16 = 60018;
+pub const METHOD_GRANT_ROLE: &str = "grant_role";
+pub const METHOD_REVOKE_ROLE: &str = "revoke_role";
+pub const CHECK_HAS_ROLE_ENTRYPOINT: &str = "check_has_role";
+pub const ARG_ROLE: &str = "role";
+pub const ROLE_ADMIN: u8 = 0;
+pub const ROLE_MINTER: u8 = 1;
+pub const ROLE_NONE: u8 = 2;
+pub const ROLE_PAUSER: u8 = 3;
+pub const ERROR_INVALID_ROLE: u16 = 60044;
+pub const METHOD_RENOUNCE_ADMIN: &str = "renounce_admin";
+pub const ADMIN_COUNT_KEY: &str = "admin_count";
+pub const ERROR_LAST_ADMIN: u16 = 60045;
+pub const METHOD_PROPOSE_ADMIN: &str = "propose_admin";
+pub const METHOD_ACCEPT_ADMIN: &str = "accept_admin";
+pub const METHOD_CANCEL_ADMIN_PROPOSAL: &str = "cancel_admin_proposal";
+pub const ERROR_NO_ADMIN_NOMINATION: u16 = 60046;
+pub const CHECK_ROLE_MEMBERS_ENTRYPOINT: &str = "check_role_members";
+pub const CHECK_ADMINS_ENTRYPOINT: &str = "check_admins";
+pub const CHECK_MINTERS_ENTRYPOINT: &str = "check_minters";
+pub const CHECK_BADGE_ENTRYPOINT: &str = "check_badge";
+pub const ARG_OFFSET: &str = "offset";
+pub const ARG_LIMIT: &str = "limit";
+pub const METHOD_INDEX_ROLES: &str = "index_roles";
+pub const ARG_ACCOUNTS: &str = "accounts";
+pub const METHOD_SET_MINTER_ALLOWANCE: &str = "set_minter_allowance";
+pub const ARG_MINTER: &str = "minter";
+pub const ARG_ALLOWANCE: &str = "allowance";
+pub const ERROR_EXCEEDS_MINTER_ALLOWANCE: u16 = 60048;
+pub const METHOD_SET_MINT_RATE_LIMIT: &str = "set_mint_rate_limit";
+pub const ARG_MINT_RATE_LIMIT: &str = "mint_rate_limit";
+pub const ARG_MINT_RATE_WINDOW: &str = "mint_rate_window";
+pub const ERROR_EXCEEDS_MINT_RATE_LIMIT: u16 = 60049;
+pub const TRANSFER_FEE: &str = "transfer_fee";
+pub const FEE_EXEMPT_LIST: &str = "fee_exempt_list";
+pub const FEE_NON_EXEMPT_LIST: &str = "fee_non_exempt_list";
+pub const ARG_FEE_RATE: &str = "fee_rate";
+pub const ARG_MAX_FEE_RATE: &str = "max_fee_rate";
+pub const ARG_TREASURY: &str = "treasury";
+pub const METHOD_SET_FEE_RATE: &str = "set_fee_rate";
+pub const METHOD_SET_TREASURY: &str = "set_treasury";
+pub const METHOD_CHANGE_FEE_EXEMPTION: &str = "change_fee_exemption";
+pub const ERROR_INVALID_FEE_RATE: u16 = 60053;
+pub const ERROR_TRANSFER_FEE_DISABLED: u16 = 60058;
+pub const METHOD_SNAPSHOT: &str = "snapshot";
+pub const ARG_SNAPSHOT_ID: &str = "snapshot_id";
+pub const CHECK_BALANCE_OF_AT_ENTRYPOINT: &str = "check_balance_of_at";
+pub const CHECK_TOTAL_SUPPLY_AT_ENTRYPOINT: &str = "check_total_supply_at";
+pub const ERROR_INVALID_SNAPSHOT_ID: u16 = 60059;
+pub const METHOD_DELEGATE: &str = "delegate";
+pub const ARG_DELEGATEE: &str = "delegatee";
+pub const ARG_BLOCKTIME: &str = "blocktime";
+pub const CHECK_VOTES_ENTRYPOINT: &str = "check_votes";
+pub const CHECK_PAST_VOTES_ENTRYPOINT: &str = "check_past_votes";
+pub const ERROR_FUTURE_LOOKUP: u16 = 60060;
+pub const METHOD_CREATE_VESTING_SCHEDULE: &str = "create_vesting_schedule";
+pub const ARG_HOLDER: &str = "holder";
+pub const ARG_START: &str = "start";
+pub const ARG_CLIFF: &str = "cliff";
+pub const ARG_DURATION: &str = "duration";
+pub const ARG_TOTAL: &str = "total";
+pub const CHECK_LOCKED_BALANCE_OF_ENTRYPOINT: &str = "check_locked_balance_of";
+pub const CHECK_VESTED_AMOUNT_ENTRYPOINT: &str = "check_vested_amount";
+pub const ERROR_INVALID_VESTING_SCHEDULE: u16 = 60061;
+pub const ERROR_VESTING_SCHEDULE_EXISTS: u16 = 60062;
+pub const ERROR_INSUFFICIENT_UNLOCKED_BALANCE: u16 = 60063;
+pub const METHOD_TRANSFER_AND_CALL: &str = "transfer_and_call";
+pub const ARG_DATA: &str = "data";
+pub const ERROR_INVALID_RECEIVER: u16 = 60064;
+pub const ERROR_TRANSFER_REJECTED: u16 = 60065;
+pub const METHOD_SAFE_TRANSFER: &str = "safe_transfer";
+pub const METHOD_SAFE_TRANSFER_FROM: &str = "safe_transfer_from";
+pub const ERROR_UNSAFE_RECIPIENT: u16 = 60066;
+pub const METHOD_REGISTER_RECEIVER: &str = "register_receiver";
+pub const METHOD_REGISTER_AS_RECEIVER: &str = "register_as_receiver";
+pub const METHOD_UNREGISTER_AS_RECEIVER: &str = "unregister_as_receiver";
+pub const METHOD_TRANSFER_WITH_MEMO: &str = "transfer_with_memo";
+pub const METHOD_TRANSFER_FROM_WITH_MEMO: &str = "transfer_from_with_memo";
+pub const ARG_MEMO: &str = "memo";
+pub const EVENTS_DICT_KEY: &str = "__events";
+pub const METHOD_FLASH_MINT: &str = "flash_mint";
+pub const ARG_BORROWER: &str = "borrower";
+pub const ARG_ENABLE_FLASH_MINT: &str = "enable_flash_mint";
+pub const ARG_FLASH_FEE_RATE: &str = "flash_fee_rate";
+pub const CHECK_MAX_FLASH_LOAN_ENTRYPOINT: &str = "check_max_flash_loan";
+pub const ERROR_FLASH_MINT_DISABLED: u16 = 60069;
+pub const ERROR_EXCEEDS_MAX_FLASH_LOAN: u16 = 60070;
+pub const ERROR_FLASH_LOAN_REJECTED: u16 = 60071;
+pub const ERROR_FLASH_LOAN_NOT_REPAID: u16 = 60072;
+pub const ARG_ENABLE_VESTING: &str = "enable_vesting";
+pub const ERROR_VESTING_DISABLED: u16 = 60074;
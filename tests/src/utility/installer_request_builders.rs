@@ -13,13 +13,18 @@ use crate::utility::constants::{
 };
 
 use super::constants::{
-    ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_ADDRESS, ARG_AMOUNT, ARG_DECIMALS, ARG_NAME, ARG_OWNER,
-    ARG_RECIPIENT, ARG_SPENDER, ARG_SYMBOL, ARG_TOKEN_CONTRACT, ARG_TOTAL_SUPPLY,
-    CEP18_CONTRACT_WASM, CEP18_TEST_CONTRACT_KEY, CEP18_TEST_CONTRACT_WASM,
-    CEP18_TOKEN_CONTRACT_KEY, CHECK_ALLOWANCE_OF_ENTRYPOINT, CHECK_BALANCE_OF_ENTRYPOINT,
-    CHECK_TOTAL_SUPPLY_ENTRYPOINT, METHOD_APPROVE, METHOD_APPROVE_AS_STORED_CONTRACT,
-    METHOD_TRANSFER, METHOD_TRANSFER_AS_STORED_CONTRACT, RESULT_KEY, TOKEN_DECIMALS, TOKEN_NAME,
-    TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+    ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_ACCOUNT, ARG_ADDRESS, ARG_AMOUNT, ARG_BLOCKTIME,
+    ARG_DECIMALS, ARG_LIMIT, ARG_NAME, ARG_OFFSET, ARG_OWNER, ARG_RECIPIENT, ARG_ROLE,
+    ARG_SNAPSHOT_ID, ARG_SPENDER, ARG_SYMBOL, ARG_TOKEN_CONTRACT, ARG_TOTAL_SUPPLY,
+    CEP18_1_1_0_CONTRACT_WASM, CEP18_CONTRACT_WASM, CEP18_TEST_CONTRACT_KEY,
+    CEP18_TEST_CONTRACT_WASM, CEP18_TOKEN_CONTRACT_KEY, CHECK_ADMINS_ENTRYPOINT,
+    CHECK_ALLOWANCE_OF_ENTRYPOINT, CHECK_BADGE_ENTRYPOINT, CHECK_BALANCE_OF_AT_ENTRYPOINT,
+    CHECK_BALANCE_OF_ENTRYPOINT, CHECK_HAS_ROLE_ENTRYPOINT, CHECK_LOCKED_BALANCE_OF_ENTRYPOINT,
+    CHECK_MAX_FLASH_LOAN_ENTRYPOINT, CHECK_MINTERS_ENTRYPOINT, CHECK_PAST_VOTES_ENTRYPOINT,
+    CHECK_ROLE_MEMBERS_ENTRYPOINT, CHECK_TOTAL_SUPPLY_AT_ENTRYPOINT, CHECK_TOTAL_SUPPLY_ENTRYPOINT,
+    CHECK_VESTED_AMOUNT_ENTRYPOINT, CHECK_VOTES_ENTRYPOINT, METHOD_APPROVE,
+    METHOD_APPROVE_AS_STORED_CONTRACT, METHOD_TRANSFER, METHOD_TRANSFER_AS_STORED_CONTRACT,
+    RESULT_KEY, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
 };
 
 /// Converts hash addr of Account into Hash, and Hash into Account
@@ -50,7 +55,35 @@ pub(crate) fn setup() -> (InMemoryWasmTestBuilder, TestContext) {
 }
 
 pub(crate) fn setup_with_args(install_args: RuntimeArgs) -> (InMemoryWasmTestBuilder, TestContext) {
-    let mut builder = InMemoryWasmTestBuilder::default();
+    setup_with_builder(InMemoryWasmTestBuilder::default(), install_args)
+}
+
+pub(crate) fn setup_with_builder(
+    builder: InMemoryWasmTestBuilder,
+    install_args: RuntimeArgs,
+) -> (InMemoryWasmTestBuilder, TestContext) {
+    let install_request =
+        ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, CEP18_CONTRACT_WASM, install_args)
+            .build();
+    setup_with_install_request(builder, install_request)
+}
+
+/// Installs release 1.1.0 of the token instead of the current one.
+pub(crate) fn setup_1_1_0_with_args(
+    install_args: RuntimeArgs,
+) -> (InMemoryWasmTestBuilder, TestContext) {
+    let module_bytes =
+        std::fs::read(CEP18_1_1_0_CONTRACT_WASM).expect("should read the 1.1.0 wasm fixture");
+    let install_request =
+        ExecuteRequestBuilder::module_bytes(*DEFAULT_ACCOUNT_ADDR, module_bytes, install_args)
+            .build();
+    setup_with_install_request(InMemoryWasmTestBuilder::default(), install_request)
+}
+
+fn setup_with_install_request(
+    mut builder: InMemoryWasmTestBuilder,
+    install_request_1: ExecuteRequest,
+) -> (InMemoryWasmTestBuilder, TestContext) {
     builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
 
     let id: Option<u64> = None;
@@ -70,10 +103,6 @@ pub(crate) fn setup_with_args(install_args: RuntimeArgs) -> (InMemoryWasmTestBui
     let transfer_request_2 =
         ExecuteRequestBuilder::transfer(*DEFAULT_ACCOUNT_ADDR, transfer_2_args).build();
 
-    let install_request_1 =
-        ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, CEP18_CONTRACT_WASM, install_args)
-            .build();
-
     let install_request_2 = ExecuteRequestBuilder::standard(
         *DEFAULT_ACCOUNT_ADDR,
         CEP18_TEST_CONTRACT_WASM,
@@ -144,6 +173,38 @@ pub(crate) fn cep18_check_total_supply(
     get_test_result(builder, cep18_test_contract_package)
 }
 
+pub(crate) fn cep18_check_max_flash_loan(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_contract_hash: &ContractHash,
+) -> U256 {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let cep18_test_contract_package = account
+        .named_keys()
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_max_flash_loan_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(*cep18_contract_hash),
+    };
+
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_MAX_FLASH_LOAN_ENTRYPOINT,
+        check_max_flash_loan_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
 pub(crate) fn get_test_result<T: FromBytes + CLTyped>(
     builder: &mut InMemoryWasmTestBuilder,
     cep18_test_contract_package: ContractPackageHash,
@@ -194,6 +255,215 @@ pub(crate) fn cep18_check_balance_of(
     get_test_result(builder, cep18_test_contract_package)
 }
 
+pub(crate) fn cep18_check_balance_of_at(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_contract_hash: &ContractHash,
+    address: Key,
+    snapshot_id: u64,
+) -> U256 {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let cep18_test_contract_package = account
+        .named_keys()
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_balance_at_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(*cep18_contract_hash),
+        ARG_ADDRESS => address,
+        ARG_SNAPSHOT_ID => snapshot_id,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_BALANCE_OF_AT_ENTRYPOINT,
+        check_balance_at_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
+pub(crate) fn cep18_check_total_supply_at(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_contract_hash: &ContractHash,
+    snapshot_id: u64,
+) -> U256 {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let cep18_test_contract_package = account
+        .named_keys()
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_total_supply_at_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(*cep18_contract_hash),
+        ARG_SNAPSHOT_ID => snapshot_id,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_TOTAL_SUPPLY_AT_ENTRYPOINT,
+        check_total_supply_at_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
+pub(crate) fn cep18_check_votes(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_contract_hash: &ContractHash,
+    account: Key,
+) -> U256 {
+    let default_account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let cep18_test_contract_package = default_account
+        .named_keys()
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_votes_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(*cep18_contract_hash),
+        ARG_ACCOUNT => account,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_VOTES_ENTRYPOINT,
+        check_votes_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
+/// Queries past votes from a block right after `blocktime`, as only elapsed blocktimes can be
+/// looked up.
+pub(crate) fn cep18_check_past_votes(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_contract_hash: &ContractHash,
+    account: Key,
+    blocktime: u64,
+) -> U256 {
+    let default_account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let cep18_test_contract_package = default_account
+        .named_keys()
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_past_votes_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(*cep18_contract_hash),
+        ARG_ACCOUNT => account,
+        ARG_BLOCKTIME => blocktime,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_PAST_VOTES_ENTRYPOINT,
+        check_past_votes_args,
+    )
+    .with_block_time(blocktime + 1)
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
+pub(crate) fn cep18_check_locked_balance_of(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_contract_hash: &ContractHash,
+    address: Key,
+    blocktime: u64,
+) -> U256 {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let cep18_test_contract_package = account
+        .named_keys()
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_locked_balance_of_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(*cep18_contract_hash),
+        ARG_ADDRESS => address,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_LOCKED_BALANCE_OF_ENTRYPOINT,
+        check_locked_balance_of_args,
+    )
+    .with_block_time(blocktime)
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
+pub(crate) fn cep18_check_vested_amount(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_contract_hash: &ContractHash,
+    address: Key,
+    blocktime: u64,
+) -> U256 {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let cep18_test_contract_package = account
+        .named_keys()
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_vested_amount_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(*cep18_contract_hash),
+        ARG_ADDRESS => address,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_VESTED_AMOUNT_ENTRYPOINT,
+        check_vested_amount_args,
+    )
+    .with_block_time(blocktime)
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
 pub(crate) fn cep18_check_allowance_of(
     builder: &mut InMemoryWasmTestBuilder,
     owner: Key,
@@ -233,6 +503,176 @@ pub(crate) fn cep18_check_allowance_of(
     get_test_result(builder, cep18_test_contract_package)
 }
 
+pub(crate) fn cep18_check_has_role(
+    builder: &mut InMemoryWasmTestBuilder,
+    account: Key,
+    role: u8,
+) -> bool {
+    let account_named_keys = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .clone();
+    let cep18_contract_hash = account_named_keys
+        .get(CEP18_TOKEN_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .expect("should have test contract hash");
+    let cep18_test_contract_package = account_named_keys
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_has_role_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(cep18_contract_hash),
+        ARG_ACCOUNT => account,
+        ARG_ROLE => role,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_HAS_ROLE_ENTRYPOINT,
+        check_has_role_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
+pub(crate) fn cep18_check_role_members(
+    builder: &mut InMemoryWasmTestBuilder,
+    role: u8,
+    offset: u32,
+    limit: u32,
+) -> Vec<Key> {
+    let account_named_keys = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .clone();
+    let cep18_contract_hash = account_named_keys
+        .get(CEP18_TOKEN_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .expect("should have test contract hash");
+    let cep18_test_contract_package = account_named_keys
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_role_members_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(cep18_contract_hash),
+        ARG_ROLE => role,
+        ARG_OFFSET => offset,
+        ARG_LIMIT => limit,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_ROLE_MEMBERS_ENTRYPOINT,
+        check_role_members_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
+pub(crate) fn cep18_check_admins(
+    builder: &mut InMemoryWasmTestBuilder,
+    offset: u32,
+    limit: u32,
+) -> Vec<Key> {
+    cep18_check_role_getter(builder, CHECK_ADMINS_ENTRYPOINT, offset, limit)
+}
+
+pub(crate) fn cep18_check_minters(
+    builder: &mut InMemoryWasmTestBuilder,
+    offset: u32,
+    limit: u32,
+) -> Vec<Key> {
+    cep18_check_role_getter(builder, CHECK_MINTERS_ENTRYPOINT, offset, limit)
+}
+
+fn cep18_check_role_getter(
+    builder: &mut InMemoryWasmTestBuilder,
+    check_entrypoint: &str,
+    offset: u32,
+    limit: u32,
+) -> Vec<Key> {
+    let account_named_keys = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .clone();
+    let cep18_contract_hash = account_named_keys
+        .get(CEP18_TOKEN_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .expect("should have test contract hash");
+    let cep18_test_contract_package = account_named_keys
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_role_getter_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(cep18_contract_hash),
+        ARG_OFFSET => offset,
+        ARG_LIMIT => limit,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        check_entrypoint,
+        check_role_getter_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
+pub(crate) fn cep18_check_badge(builder: &mut InMemoryWasmTestBuilder, account: Option<Key>) -> u8 {
+    let account_named_keys = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .clone();
+    let cep18_contract_hash = account_named_keys
+        .get(CEP18_TOKEN_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .expect("should have test contract hash");
+    let cep18_test_contract_package = account_named_keys
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_badge_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(cep18_contract_hash),
+        ARG_ACCOUNT => account,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_BADGE_ENTRYPOINT,
+        check_badge_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
 pub(crate) fn test_cep18_transfer(
     builder: &mut InMemoryWasmTestBuilder,
     test_context: &TestContext,
@@ -0,0 +1,359 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ARG_AMOUNT, ARG_CLIFF, ARG_DECIMALS, ARG_DURATION, ARG_ENABLE_VESTING,
+        ARG_HOLDER, ARG_NAME, ARG_OWNER, ARG_RECIPIENT, ARG_START, ARG_SYMBOL, ARG_TOTAL,
+        ARG_TOTAL_SUPPLY, ENABLE_MINT_BURN, ERROR_INSUFFICIENT_RIGHTS,
+        ERROR_INSUFFICIENT_UNLOCKED_BALANCE, ERROR_INVALID_VESTING_SCHEDULE,
+        ERROR_VESTING_DISABLED, ERROR_VESTING_SCHEDULE_EXISTS, METHOD_BURN,
+        METHOD_CREATE_VESTING_SCHEDULE, METHOD_TRANSFER, TOKEN_DECIMALS, TOKEN_NAME,
+        TOKEN_OWNER_ADDRESS_1, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+    },
+    installer_request_builders::{
+        cep18_check_balance_of, cep18_check_locked_balance_of, cep18_check_vested_amount,
+        make_cep18_transfer_request, setup, setup_with_args, TestContext,
+    },
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+const START: u64 = 1_000;
+const CLIFF: u64 = 100;
+const DURATION: u64 = 1_000;
+const VESTED_TOTAL: u64 = 1_000;
+const PRIOR_BALANCE: u64 = 500;
+
+#[test]
+fn should_only_spend_unlocked_balance() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        ARG_ENABLE_VESTING => 1u8,
+    });
+    let holder = Key::Account(*ACCOUNT_1_ADDR);
+
+    let create_vesting_schedule_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_CREATE_VESTING_SCHEDULE,
+        runtime_args! {
+            ARG_HOLDER => holder,
+            ARG_START => START,
+            ARG_CLIFF => CLIFF,
+            ARG_DURATION => DURATION,
+            ARG_TOTAL => U256::from(VESTED_TOTAL),
+        },
+    )
+    .build();
+    builder
+        .exec(create_vesting_schedule_request)
+        .expect_success()
+        .commit();
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, holder),
+        U256::from(VESTED_TOTAL)
+    );
+    assert_eq!(
+        cep18_check_balance_of(
+            &mut builder,
+            &cep18_token,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR)
+        ),
+        U256::from(TOKEN_TOTAL_SUPPLY - VESTED_TOTAL)
+    );
+
+    let holder_transfer_request = |amount: u64, blocktime: u64| {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            *ACCOUNT_1_ADDR,
+            cep18_token,
+            METHOD_TRANSFER,
+            runtime_args! {
+                ARG_RECIPIENT => TOKEN_OWNER_ADDRESS_1,
+                ARG_AMOUNT => U256::from(amount),
+            },
+        )
+        .with_block_time(blocktime)
+        .build()
+    };
+    let assert_insufficient_unlocked_balance = |error: CoreError| {
+        assert!(
+            matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_UNLOCKED_BALANCE),
+            "{:?}",
+            error
+        );
+    };
+
+    let before_cliff = START + CLIFF - 1;
+    assert_eq!(
+        cep18_check_vested_amount(&mut builder, &cep18_token, holder, before_cliff),
+        U256::zero()
+    );
+    assert_eq!(
+        cep18_check_locked_balance_of(&mut builder, &cep18_token, holder, before_cliff),
+        U256::from(VESTED_TOTAL)
+    );
+    builder
+        .exec(holder_transfer_request(1, before_cliff))
+        .commit();
+    assert_insufficient_unlocked_balance(builder.get_error().expect("should have error"));
+
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_BURN,
+        runtime_args! {
+            ARG_OWNER => holder,
+            ARG_AMOUNT => U256::one(),
+        },
+    )
+    .with_block_time(before_cliff)
+    .build();
+    builder.exec(burn_request).commit();
+    assert_insufficient_unlocked_balance(builder.get_error().expect("should have error"));
+
+    let halfway = START + DURATION / 2;
+    assert_eq!(
+        cep18_check_vested_amount(&mut builder, &cep18_token, holder, halfway),
+        U256::from(VESTED_TOTAL / 2)
+    );
+    assert_eq!(
+        cep18_check_locked_balance_of(&mut builder, &cep18_token, holder, halfway),
+        U256::from(VESTED_TOTAL / 2)
+    );
+    builder
+        .exec(holder_transfer_request(VESTED_TOTAL / 2, halfway))
+        .expect_success()
+        .commit();
+    builder.exec(holder_transfer_request(1, halfway)).commit();
+    assert_insufficient_unlocked_balance(builder.get_error().expect("should have error"));
+
+    let end = START + DURATION;
+    assert_eq!(
+        cep18_check_locked_balance_of(&mut builder, &cep18_token, holder, end),
+        U256::zero()
+    );
+    builder
+        .exec(holder_transfer_request(VESTED_TOTAL / 2, end))
+        .expect_success()
+        .commit();
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, TOKEN_OWNER_ADDRESS_1),
+        U256::from(VESTED_TOTAL)
+    );
+}
+
+#[test]
+fn should_keep_prior_balance_spendable() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ARG_ENABLE_VESTING => 1u8,
+    });
+    let holder = Key::Account(*ACCOUNT_1_ADDR);
+
+    let transfer_request = make_cep18_transfer_request(
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        &cep18_token,
+        holder,
+        U256::from(PRIOR_BALANCE),
+    );
+    builder.exec(transfer_request).expect_success().commit();
+
+    let create_vesting_schedule_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_CREATE_VESTING_SCHEDULE,
+        runtime_args! {
+            ARG_HOLDER => holder,
+            ARG_START => START,
+            ARG_CLIFF => CLIFF,
+            ARG_DURATION => DURATION,
+            ARG_TOTAL => U256::from(VESTED_TOTAL),
+        },
+    )
+    .build();
+    builder
+        .exec(create_vesting_schedule_request)
+        .expect_success()
+        .commit();
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, holder),
+        U256::from(PRIOR_BALANCE + VESTED_TOTAL)
+    );
+
+    // Only the granted tokens are locked, the prior balance can be spent before the cliff.
+    let holder_transfer_request = |amount: u64| {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            *ACCOUNT_1_ADDR,
+            cep18_token,
+            METHOD_TRANSFER,
+            runtime_args! {
+                ARG_RECIPIENT => TOKEN_OWNER_ADDRESS_1,
+                ARG_AMOUNT => U256::from(amount),
+            },
+        )
+        .with_block_time(START)
+        .build()
+    };
+    builder
+        .exec(holder_transfer_request(PRIOR_BALANCE))
+        .expect_success()
+        .commit();
+    builder.exec(holder_transfer_request(1)).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_UNLOCKED_BALANCE),
+        "{:?}",
+        error
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, holder),
+        U256::from(VESTED_TOTAL)
+    );
+}
+
+#[test]
+fn should_only_create_valid_vesting_schedule_by_admin() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ARG_ENABLE_VESTING => 1u8,
+    });
+
+    let create_vesting_schedule_request = |sender, start: u64, cliff: u64, blocktime: u64| {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            sender,
+            cep18_token,
+            METHOD_CREATE_VESTING_SCHEDULE,
+            runtime_args! {
+                ARG_HOLDER => TOKEN_OWNER_ADDRESS_1,
+                ARG_START => start,
+                ARG_CLIFF => cliff,
+                ARG_DURATION => DURATION,
+                ARG_TOTAL => U256::from(VESTED_TOTAL),
+            },
+        )
+        .with_block_time(blocktime)
+        .build()
+    };
+    // About ten years, the longest a schedule can run from the current block time.
+    let max_vesting_period = 10 * 365 * 24 * 60 * 60 * 1_000;
+
+    let expected_errors = [
+        (*ACCOUNT_1_ADDR, START, CLIFF, ERROR_INSUFFICIENT_RIGHTS),
+        (
+            *DEFAULT_ACCOUNT_ADDR,
+            START,
+            DURATION + 1,
+            ERROR_INVALID_VESTING_SCHEDULE,
+        ),
+        (
+            *DEFAULT_ACCOUNT_ADDR,
+            max_vesting_period,
+            CLIFF,
+            ERROR_INVALID_VESTING_SCHEDULE,
+        ),
+        (
+            *DEFAULT_ACCOUNT_ADDR,
+            u64::MAX,
+            CLIFF,
+            ERROR_INVALID_VESTING_SCHEDULE,
+        ),
+    ];
+    for (sender, start, cliff, expected_error) in expected_errors {
+        builder
+            .exec(create_vesting_schedule_request(sender, start, cliff, 0))
+            .commit();
+
+        let error = builder.get_error().expect("should have error");
+        assert!(
+            matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == expected_error),
+            "{:?}",
+            error
+        );
+    }
+
+    builder
+        .exec(create_vesting_schedule_request(
+            *DEFAULT_ACCOUNT_ADDR,
+            START,
+            CLIFF,
+            0,
+        ))
+        .expect_success()
+        .commit();
+    builder
+        .exec(create_vesting_schedule_request(
+            *DEFAULT_ACCOUNT_ADDR,
+            START,
+            CLIFF,
+            START + DURATION - 1,
+        ))
+        .commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_VESTING_SCHEDULE_EXISTS),
+        "{:?}",
+        error
+    );
+
+    // A fully vested schedule can be replaced.
+    let end = START + DURATION;
+    builder
+        .exec(create_vesting_schedule_request(
+            *DEFAULT_ACCOUNT_ADDR,
+            end,
+            CLIFF,
+            end,
+        ))
+        .expect_success()
+        .commit();
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, TOKEN_OWNER_ADDRESS_1),
+        U256::from(2 * VESTED_TOTAL)
+    );
+    assert_eq!(
+        cep18_check_locked_balance_of(&mut builder, &cep18_token, TOKEN_OWNER_ADDRESS_1, end),
+        U256::from(VESTED_TOTAL)
+    );
+}
+
+#[test]
+fn should_not_create_vesting_schedule_with_vesting_disabled() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    let create_vesting_schedule_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_CREATE_VESTING_SCHEDULE,
+        runtime_args! {
+            ARG_HOLDER => TOKEN_OWNER_ADDRESS_1,
+            ARG_START => START,
+            ARG_CLIFF => CLIFF,
+            ARG_DURATION => DURATION,
+            ARG_TOTAL => U256::from(VESTED_TOTAL),
+        },
+    )
+    .build();
+    builder.exec(create_vesting_schedule_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_VESTING_DISABLED),
+        "{:?}",
+        error
+    );
+}
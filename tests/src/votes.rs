@@ -0,0 +1,180 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_ACCOUNT, ARG_AMOUNT, ARG_BLOCKTIME, ARG_DECIMALS,
+        ARG_DELEGATEE, ARG_NAME, ARG_OWNER, ARG_RECIPIENT, ARG_SYMBOL, ARG_TOKEN_CONTRACT,
+        ARG_TOTAL_SUPPLY, CHECK_PAST_VOTES_ENTRYPOINT, ENABLE_MINT_BURN, ERROR_FUTURE_LOOKUP,
+        METHOD_BURN, METHOD_DELEGATE, METHOD_MINT, METHOD_TRANSFER, TOKEN_DECIMALS, TOKEN_NAME,
+        TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+    },
+    installer_request_builders::{
+        cep18_check_past_votes, cep18_check_votes, setup, setup_with_args, TestContext,
+    },
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+#[test]
+fn should_track_delegated_votes_through_transfers_mints_and_burns() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+    });
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let account_1 = Key::Account(*ACCOUNT_1_ADDR);
+    let account_2 = Key::Account(*ACCOUNT_2_ADDR);
+
+    let self_delegate_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_DELEGATE,
+        runtime_args! {
+            ARG_DELEGATEE => owner,
+        },
+    )
+    .with_block_time(100)
+    .build();
+    builder
+        .exec(self_delegate_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        cep18_check_votes(&mut builder, &cep18_token, owner),
+        U256::from(TOKEN_TOTAL_SUPPLY)
+    );
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENT => account_1,
+            ARG_AMOUNT => U256::from(1_000),
+        },
+    )
+    .with_block_time(200)
+    .build();
+    builder.exec(transfer_request).expect_success().commit();
+
+    let delegate_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_DELEGATE,
+        runtime_args! {
+            ARG_DELEGATEE => account_2,
+        },
+    )
+    .with_block_time(300)
+    .build();
+    builder.exec(delegate_request).expect_success().commit();
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => account_1,
+            ARG_AMOUNT => U256::from(500),
+        },
+    )
+    .with_block_time(400)
+    .build();
+    builder.exec(mint_request).expect_success().commit();
+
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_BURN,
+        runtime_args! {
+            ARG_OWNER => account_1,
+            ARG_AMOUNT => U256::from(200),
+        },
+    )
+    .with_block_time(500)
+    .build();
+    builder.exec(burn_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_votes(&mut builder, &cep18_token, owner),
+        U256::from(TOKEN_TOTAL_SUPPLY) - U256::from(1_000)
+    );
+    assert_eq!(
+        cep18_check_votes(&mut builder, &cep18_token, account_1),
+        U256::zero()
+    );
+    assert_eq!(
+        cep18_check_votes(&mut builder, &cep18_token, account_2),
+        U256::from(1_300)
+    );
+
+    assert_eq!(
+        cep18_check_past_votes(&mut builder, &cep18_token, owner, 99),
+        U256::zero()
+    );
+    assert_eq!(
+        cep18_check_past_votes(&mut builder, &cep18_token, owner, 150),
+        U256::from(TOKEN_TOTAL_SUPPLY)
+    );
+    assert_eq!(
+        cep18_check_past_votes(&mut builder, &cep18_token, owner, 250),
+        U256::from(TOKEN_TOTAL_SUPPLY) - U256::from(1_000)
+    );
+    assert_eq!(
+        cep18_check_past_votes(&mut builder, &cep18_token, account_2, 299),
+        U256::zero()
+    );
+    assert_eq!(
+        cep18_check_past_votes(&mut builder, &cep18_token, account_2, 350),
+        U256::from(1_000)
+    );
+    assert_eq!(
+        cep18_check_past_votes(&mut builder, &cep18_token, account_2, 450),
+        U256::from(1_500)
+    );
+    assert_eq!(
+        cep18_check_past_votes(&mut builder, &cep18_token, account_2, 500),
+        U256::from(1_300)
+    );
+}
+
+#[test]
+fn should_not_look_up_votes_at_current_or_future_blocktime() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_token,
+            cep18_test_contract_package,
+        },
+    ) = setup();
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    let past_votes_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_PAST_VOTES_ENTRYPOINT,
+        runtime_args! {
+            ARG_TOKEN_CONTRACT => Key::from(cep18_token),
+            ARG_ACCOUNT => owner,
+            ARG_BLOCKTIME => 1_000u64,
+        },
+    )
+    .with_block_time(1_000)
+    .build();
+    builder.exec(past_votes_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_FUTURE_LOOKUP),
+        "{:?}",
+        error
+    );
+}